@@ -0,0 +1,237 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::AppError;
+use crate::export::CsvOptions;
+
+use super::BatchReadResult;
+
+/// When and where to flush a collection buffer to disk once it grows
+/// too large to keep entirely in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoFlushPolicy {
+    pub max_buffer_size: usize,
+    pub export_path: PathBuf,
+}
+
+/// If `buffer` has reached `policy.max_buffer_size`, write its contents
+/// to `policy.export_path` via `write_csv` and clear it so collection
+/// can keep appending without growing memory further. `buffer` is only
+/// cleared after a successful write, and the caller is expected to hold
+/// it locked for the whole call, so no sample can be lost between the
+/// flush and the next push. Returns whether a flush happened.
+pub fn maybe_flush<F>(
+    buffer: &mut Vec<BatchReadResult>,
+    policy: &AutoFlushPolicy,
+    mut write_csv: F,
+) -> Result<bool, AppError>
+where
+    F: FnMut(&Path, &[BatchReadResult]) -> Result<(), AppError>,
+{
+    if buffer.len() < policy.max_buffer_size {
+        return Ok(false);
+    }
+
+    write_csv(&policy.export_path, buffer)?;
+    buffer.clear();
+    Ok(true)
+}
+
+/// The production `write_csv` callback for [`maybe_flush`]: appends
+/// every batch to the CSV file at `path`, writing a header first if the
+/// file does not exist yet. One row per `(range, registers)` reading.
+/// `options` controls the delimiter, text encoding, and BOM; `None`
+/// keeps the historical comma/UTF-8/BOM format.
+pub fn append_batches_to_csv(path: &Path, batches: &[BatchReadResult], options: Option<&CsvOptions>) -> Result<(), AppError> {
+    let default_options = CsvOptions::default();
+    let options = options.unwrap_or(&default_options);
+    let file_exists = path.exists();
+
+    let mut body = Vec::new();
+    {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(options.delimiter as u8)
+            .has_headers(false)
+            .from_writer(&mut body);
+
+        if !file_exists {
+            writer
+                .write_record(["at", "range_start", "range_count", "registers"])
+                .map_err(|err| AppError::InvalidConfig(err.to_string()))?;
+        }
+
+        for batch in batches {
+            for (range, registers) in &batch.readings {
+                let values = registers.iter().map(u16::to_string).collect::<Vec<_>>().join(";");
+                writer
+                    .write_record([
+                        options.format_timestamp(batch.at),
+                        range.start.to_string(),
+                        range.count.to_string(),
+                        values,
+                    ])
+                    .map_err(|err| AppError::InvalidConfig(err.to_string()))?;
+            }
+        }
+        writer.flush()?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if !file_exists {
+        file.write_all(options.bom())?;
+    }
+    file.write_all(&options.encode(&String::from_utf8_lossy(&body)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    use crate::modbus::AddressRange;
+
+    fn batch() -> BatchReadResult {
+        BatchReadResult {
+            at: DateTime::from_timestamp(0, 0).unwrap(),
+            readings: vec![],
+            actual_interval_ms: None,
+        }
+    }
+
+    fn unique_temp_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("modbus_recoder_auto_flush_{name}.csv"));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn policy(max_buffer_size: usize) -> AutoFlushPolicy {
+        AutoFlushPolicy {
+            max_buffer_size,
+            export_path: PathBuf::from("/tmp/collection-buffer.csv"),
+        }
+    }
+
+    #[test]
+    fn flushes_and_clears_once_capacity_is_reached() {
+        let mut buffer = vec![batch(), batch()];
+        let mut written = None;
+
+        let flushed = maybe_flush(&mut buffer, &policy(2), |path, data| {
+            written = Some((path.to_path_buf(), data.len()));
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(flushed);
+        assert!(buffer.is_empty());
+        assert_eq!(written, Some((PathBuf::from("/tmp/collection-buffer.csv"), 2)));
+    }
+
+    #[test]
+    fn does_nothing_below_capacity() {
+        let mut buffer = vec![batch()];
+
+        let flushed = maybe_flush(&mut buffer, &policy(2), |_, _| {
+            panic!("write_csv should not be called below capacity")
+        })
+        .unwrap();
+
+        assert!(!flushed);
+        assert_eq!(buffer.len(), 1);
+    }
+
+    #[test]
+    fn a_failed_write_leaves_the_buffer_intact() {
+        let mut buffer = vec![batch(), batch()];
+
+        let result = maybe_flush(&mut buffer, &policy(2), |_, _| {
+            Err(AppError::Io(std::io::Error::other("disk full")))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(buffer.len(), 2);
+    }
+
+    #[test]
+    fn append_batches_to_csv_writes_a_header_once_and_then_appends() {
+        let path = unique_temp_file("append");
+        let first = BatchReadResult {
+            at: DateTime::from_timestamp(0, 0).unwrap(),
+            readings: vec![(AddressRange { start: 0, count: 2, slave_id: None }, vec![1, 2])],
+            actual_interval_ms: None,
+        };
+        let second = BatchReadResult {
+            at: DateTime::from_timestamp(1, 0).unwrap(),
+            readings: vec![(AddressRange { start: 10, count: 1, slave_id: None }, vec![7])],
+            actual_interval_ms: None,
+        };
+
+        append_batches_to_csv(&path, &[first], None).unwrap();
+        append_batches_to_csv(&path, &[second], None).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines[0].trim_start_matches('\u{FEFF}'), "at,range_start,range_count,registers");
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].contains("1;2"));
+        assert!(lines[2].contains("7"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn append_batches_to_csv_defaults_to_a_leading_utf8_bom() {
+        let path = unique_temp_file("bom");
+        append_batches_to_csv(&path, &[batch()], None).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(&bytes[0..3], &[0xEF, 0xBB, 0xBF]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn custom_options_apply_a_different_delimiter_encoding_and_skip_the_bom() {
+        let path = unique_temp_file("custom-options");
+        let options = CsvOptions {
+            delimiter: ';',
+            encoding: "GBK".to_string(),
+            with_bom: false,
+            timestamp_zone: crate::export::TimestampZone::Utc,
+        };
+
+        append_batches_to_csv(&path, &[batch()], Some(&options)).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert_ne!(bytes.get(0..3), Some(&[0xEF, 0xBB, 0xBF][..]));
+        let (decoded, _, had_errors) = encoding_rs::GBK.decode(&bytes);
+        assert!(!had_errors);
+        assert!(decoded.contains("at;range_start;range_count;registers"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn a_local_timestamp_zone_is_rendered_with_millisecond_precision_preserved() {
+        let path = unique_temp_file("local-timestamps");
+        let options = CsvOptions {
+            timestamp_zone: crate::export::TimestampZone::Local,
+            ..CsvOptions::default()
+        };
+        let batch = BatchReadResult {
+            at: DateTime::from_timestamp(0, 1_000_000).unwrap(),
+            readings: vec![(AddressRange { start: 0, count: 1, slave_id: None }, vec![1])],
+            actual_interval_ms: None,
+        };
+
+        append_batches_to_csv(&path, &[batch], Some(&options)).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let row = contents.lines().nth(1).unwrap();
+        assert!(row.contains(".001"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,162 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::AppError;
+use crate::modbus::AddressRange;
+
+use super::BatchReadResult;
+
+/// Current size of a collection buffer, for dashboards and for
+/// [`enforce_memory_limit`] to compare against a configured budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BufferStats {
+    pub entry_count: usize,
+    pub estimated_bytes: usize,
+}
+
+/// Estimate `buffer`'s in-memory footprint: each [`BatchReadResult`]'s
+/// fixed overhead plus its readings' address ranges and register
+/// values. Good enough to guard against unbounded growth during a long
+/// run; not an exact heap accounting.
+pub fn estimate_buffer_stats(buffer: &[BatchReadResult]) -> BufferStats {
+    let estimated_bytes = buffer
+        .iter()
+        .map(|batch| {
+            std::mem::size_of::<BatchReadResult>()
+                + batch
+                    .readings
+                    .iter()
+                    .map(|(_, registers)| std::mem::size_of::<(AddressRange, Vec<u16>)>() + registers.len() * std::mem::size_of::<u16>())
+                    .sum::<usize>()
+        })
+        .sum();
+
+    BufferStats { entry_count: buffer.len(), estimated_bytes }
+}
+
+/// How a collection buffer reacts once it grows past
+/// [`BufferMemoryLimit::max_bytes`], so a long-running session can't
+/// accumulate memory without bound (see exemplar reports of this
+/// happening) without forcing every caller to pick a disk flush even
+/// when dropping old samples is acceptable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryLimitPolicy {
+    /// Discard the oldest buffered batches until the buffer is back
+    /// under budget. Cheap, but loses whatever is dropped.
+    DropOldest,
+    /// Flush the whole buffer to `export_path` and clear it, the same
+    /// as [`super::maybe_flush`] does at a size threshold, so nothing
+    /// collected is lost.
+    BlockUntilExport,
+}
+
+/// A byte budget for a collection buffer and what to do once it's
+/// exceeded. Mirrors [`super::AutoFlushPolicy`]'s shape; the two are
+/// independent and can be combined (a count-based flush and a
+/// byte-based fallback for oversized batches).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferMemoryLimit {
+    pub max_bytes: usize,
+    pub policy: MemoryLimitPolicy,
+    pub export_path: PathBuf,
+}
+
+/// If `buffer`'s estimated size exceeds `limit.max_bytes`, apply
+/// `limit.policy`: drop the oldest batches, or write everything out via
+/// `write_csv` and clear it. The caller is expected to hold `buffer`
+/// locked for the whole call, same as [`super::maybe_flush`]. Returns
+/// whether anything was dropped or flushed.
+pub fn enforce_memory_limit<F>(buffer: &mut Vec<BatchReadResult>, limit: &BufferMemoryLimit, mut write_csv: F) -> Result<bool, AppError>
+where
+    F: FnMut(&Path, &[BatchReadResult]) -> Result<(), AppError>,
+{
+    if estimate_buffer_stats(buffer).estimated_bytes <= limit.max_bytes {
+        return Ok(false);
+    }
+
+    match limit.policy {
+        MemoryLimitPolicy::DropOldest => {
+            while !buffer.is_empty() && estimate_buffer_stats(buffer).estimated_bytes > limit.max_bytes {
+                buffer.remove(0);
+            }
+        }
+        MemoryLimitPolicy::BlockUntilExport => {
+            write_csv(&limit.export_path, buffer)?;
+            buffer.clear();
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    fn batch_with_registers(count: usize) -> BatchReadResult {
+        BatchReadResult {
+            at: DateTime::from_timestamp(0, 0).unwrap(),
+            readings: vec![(AddressRange { start: 0, count: count as u16, slave_id: None }, vec![0; count])],
+            actual_interval_ms: None,
+        }
+    }
+
+    fn limit(max_bytes: usize, policy: MemoryLimitPolicy) -> BufferMemoryLimit {
+        BufferMemoryLimit { max_bytes, policy, export_path: PathBuf::from("/tmp/unused.csv") }
+    }
+
+    #[test]
+    fn a_buffer_under_budget_is_left_untouched() {
+        let mut buffer = vec![batch_with_registers(1)];
+
+        let changed = enforce_memory_limit(&mut buffer, &limit(usize::MAX, MemoryLimitPolicy::DropOldest), |_, _| {
+            panic!("write_csv should not be called under budget")
+        })
+        .unwrap();
+
+        assert!(!changed);
+        assert_eq!(buffer.len(), 1);
+    }
+
+    #[test]
+    fn drop_oldest_removes_from_the_front_until_back_under_budget() {
+        let mut buffer = vec![batch_with_registers(100), batch_with_registers(100), batch_with_registers(100)];
+        let budget = estimate_buffer_stats(&buffer[1..]).estimated_bytes;
+
+        let changed = enforce_memory_limit(&mut buffer, &limit(budget, MemoryLimitPolicy::DropOldest), |_, _| {
+            panic!("write_csv should not be called for DropOldest")
+        })
+        .unwrap();
+
+        assert!(changed);
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(estimate_buffer_stats(&buffer).estimated_bytes, budget);
+    }
+
+    #[test]
+    fn block_until_export_flushes_and_clears_the_whole_buffer() {
+        let mut buffer = vec![batch_with_registers(100), batch_with_registers(100)];
+        let mut written = None;
+
+        let changed = enforce_memory_limit(&mut buffer, &limit(1, MemoryLimitPolicy::BlockUntilExport), |path, data| {
+            written = Some((path.to_path_buf(), data.len()));
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(changed);
+        assert!(buffer.is_empty());
+        assert_eq!(written, Some((PathBuf::from("/tmp/unused.csv"), 2)));
+    }
+
+    #[test]
+    fn a_failed_export_flush_leaves_the_buffer_intact() {
+        let mut buffer = vec![batch_with_registers(100)];
+
+        let result = enforce_memory_limit(&mut buffer, &limit(1, MemoryLimitPolicy::BlockUntilExport), |_, _| {
+            Err(AppError::Io(std::io::Error::other("disk full")))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(buffer.len(), 1);
+    }
+}
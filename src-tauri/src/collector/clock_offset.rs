@@ -0,0 +1,54 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// Compensates collection timestamps by a known clock offset so
+/// readings taken on different machines can be correlated against a
+/// common time base. The offset itself is expected to come from an
+/// external source (a configured value, or an NTP query result) — this
+/// type only applies it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockOffset {
+    offset_ms: i64,
+}
+
+impl ClockOffset {
+    pub fn new(offset_ms: i64) -> Self {
+        Self { offset_ms }
+    }
+
+    /// No compensation: the local clock is trusted as-is.
+    pub fn zero() -> Self {
+        Self { offset_ms: 0 }
+    }
+
+    /// Apply the configured offset to a locally-observed timestamp.
+    pub fn apply(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        at + Duration::milliseconds(self.offset_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn zero_offset_leaves_timestamp_unchanged() {
+        let offset = ClockOffset::zero();
+        assert_eq!(offset.apply(at(100)), at(100));
+    }
+
+    #[test]
+    fn positive_offset_shifts_timestamp_forward() {
+        let offset = ClockOffset::new(1500);
+        assert_eq!(offset.apply(at(100)), at(100) + Duration::milliseconds(1500));
+    }
+
+    #[test]
+    fn negative_offset_shifts_timestamp_backward() {
+        let offset = ClockOffset::new(-2000);
+        assert_eq!(offset.apply(at(100)), at(98));
+    }
+}
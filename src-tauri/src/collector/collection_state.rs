@@ -0,0 +1,10 @@
+/// The lifecycle state of a [`super::DataCollector`]'s background loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionState {
+    Running,
+    Paused,
+    Stopped,
+    /// The connection was found unresponsive (e.g. by
+    /// [`super::KeepaliveTask`]) and a reconnect is in progress.
+    Error,
+}
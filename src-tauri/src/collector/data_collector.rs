@@ -0,0 +1,598 @@
+use std::future::Future;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use crate::error::AppError;
+use crate::modbus::AddressRange;
+
+use super::{enforce_memory_limit, maybe_flush, AutoFlushPolicy, BufferMemoryLimit, BufferStats, CollectionEventSink, CollectionState};
+
+/// The `write_csv` callback [`DataCollector::start`] invokes when the
+/// buffer reaches an [`AutoFlushPolicy`]'s limit. Boxed so callers can
+/// pass either [`super::append_batches_to_csv`] or a test double.
+pub type AutoFlushWriter = Arc<dyn Fn(&Path, &[BatchReadResult]) -> Result<(), AppError> + Send + Sync>;
+
+/// One polling cycle's worth of readings, one entry per configured
+/// [`AddressRange`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatchReadResult {
+    pub at: DateTime<Utc>,
+    pub readings: Vec<(AddressRange, Vec<u16>)>,
+    /// Milliseconds since the previous tick was handled, for diagnosing
+    /// whether collection is keeping up with its configured interval.
+    /// `None` for the first batch of a session, since there is no
+    /// previous tick to measure from.
+    pub actual_interval_ms: Option<i64>,
+}
+
+/// Runs a background polling loop that periodically reads the
+/// configured ranges and caches every batch, without blocking the
+/// caller. `stop` cancels the loop but leaves everything collected so
+/// far in the buffer.
+///
+/// A cycle that fails with [`AppError::NotConnected`] (the device was
+/// disconnected while collection was running) auto-[`Self::pause`]s
+/// instead of retrying every tick: there's no [`crate::modbus::ConnectionSequencer`]
+/// reference here to reconnect through, so the caller that owns both is
+/// expected to [`Self::resume`] once it reconnects.
+pub struct DataCollector {
+    buffer: Arc<Mutex<Vec<BatchReadResult>>>,
+    running: Arc<AtomicBool>,
+    state: Arc<StdMutex<CollectionState>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Default for DataCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataCollector {
+    pub fn new() -> Self {
+        Self {
+            buffer: Arc::new(Mutex::new(Vec::new())),
+            running: Arc::new(AtomicBool::new(false)),
+            state: Arc::new(StdMutex::new(CollectionState::Stopped)),
+            handle: None,
+        }
+    }
+
+    /// Start polling every `interval_ms` milliseconds, calling
+    /// `read_ranges_detailed` on each tick and appending a successful
+    /// result to the buffer. A failed cycle is reported to `events` (if
+    /// given) and the loop keeps running rather than stopping. If
+    /// `auto_flush` is given, the buffer is written out and cleared via
+    /// its writer once it reaches the policy's limit, so a long-running
+    /// session doesn't grow memory without bound; a failed flush is also
+    /// reported to `events` rather than stopping collection. `memory_limit`,
+    /// if given, additionally caps the buffer's estimated byte size
+    /// (independent of `auto_flush`'s entry-count limit) by dropping the
+    /// oldest batches or forcing an export, per
+    /// [`BufferMemoryLimit::policy`]. A no-op if the collector is already
+    /// running.
+    ///
+    /// `missed_tick_policy`, if given, is applied to the underlying
+    /// [`tokio::time::interval`] ticker via
+    /// [`tokio::time::Interval::set_missed_tick_behavior`] — in
+    /// particular, `Some(MissedTickBehavior::Skip)` makes a read that
+    /// overruns `interval_ms` skip the ticks it missed instead of firing
+    /// them back-to-back once it finishes, so a slow cycle falls behind
+    /// the clock rather than queuing up a burst of catch-up reads. `None`
+    /// leaves tokio's own default (`Burst`) in place. Every batch also
+    /// carries the actual elapsed time since the previous tick in
+    /// [`BatchReadResult::actual_interval_ms`], regardless of this
+    /// setting, so a caller can diagnose drift either way.
+    pub fn start<F, Fut>(
+        &mut self,
+        interval_ms: u64,
+        read_ranges_detailed: F,
+        events: Option<Arc<dyn CollectionEventSink>>,
+        auto_flush: Option<(AutoFlushPolicy, AutoFlushWriter)>,
+        memory_limit: Option<(BufferMemoryLimit, AutoFlushWriter)>,
+        missed_tick_policy: Option<MissedTickBehavior>,
+    ) where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<BatchReadResult, AppError>> + Send + 'static,
+    {
+        if self.handle.is_some() {
+            return;
+        }
+
+        self.running.store(true, Ordering::SeqCst);
+        *self.state.lock().unwrap() = CollectionState::Running;
+        let buffer = Arc::clone(&self.buffer);
+        let running = Arc::clone(&self.running);
+        let state = Arc::clone(&self.state);
+
+        self.handle = Some(tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_millis(interval_ms));
+            if let Some(policy) = missed_tick_policy {
+                ticker.set_missed_tick_behavior(policy);
+            }
+            let mut last_tick_at: Option<DateTime<Utc>> = None;
+            while running.load(Ordering::SeqCst) {
+                ticker.tick().await;
+                if !running.load(Ordering::SeqCst) {
+                    break;
+                }
+                if *state.lock().unwrap() == CollectionState::Paused {
+                    continue;
+                }
+                let now = Utc::now();
+                let actual_interval_ms = last_tick_at.map(|prev| (now - prev).num_milliseconds());
+                last_tick_at = Some(now);
+                match read_ranges_detailed().await {
+                    Ok(mut batch) => {
+                        batch.actual_interval_ms = actual_interval_ms;
+                        if let Some(sink) = &events {
+                            sink.on_batch(&batch);
+                        }
+                        // Hold the lock across the push and both limit
+                        // checks so no batch from a later tick can slip
+                        // in between a flush/drop and the next push.
+                        let mut guard = buffer.lock().await;
+                        guard.push(batch);
+                        if let Some((policy, write_csv)) = &auto_flush {
+                            if let Err(err) = maybe_flush(&mut guard, policy, |path, data| write_csv(path, data)) {
+                                if let Some(sink) = &events {
+                                    sink.on_error(&err.to_string());
+                                }
+                            }
+                        }
+                        if let Some((limit, write_csv)) = &memory_limit {
+                            if let Err(err) = enforce_memory_limit(&mut guard, limit, |path, data| write_csv(path, data)) {
+                                if let Some(sink) = &events {
+                                    sink.on_error(&err.to_string());
+                                }
+                            }
+                        }
+                    }
+                    Err(AppError::NotConnected) => {
+                        // The device was disconnected out from under a
+                        // running collection. Pause instead of looping
+                        // straight back into the same failure every
+                        // tick — there is nothing a retry can do until
+                        // something reconnects and calls `resume`.
+                        *state.lock().unwrap() = CollectionState::Paused;
+                        if let Some(sink) = &events {
+                            sink.on_error(&AppError::NotConnected.to_string());
+                        }
+                    }
+                    Err(err) => {
+                        if let Some(sink) = &events {
+                            sink.on_error(&err.to_string());
+                        }
+                    }
+                }
+            }
+        }));
+    }
+
+    /// Cancel the background polling loop, if running, and wait for it
+    /// to wind down. Everything collected so far remains in the buffer.
+    pub async fn stop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+        *self.state.lock().unwrap() = CollectionState::Stopped;
+    }
+
+    /// Pause the background loop: it keeps ticking at `interval_ms` but
+    /// skips every actual read, leaving the buffer untouched until
+    /// [`Self::resume`] is called.
+    pub fn pause(&self) {
+        *self.state.lock().unwrap() = CollectionState::Paused;
+    }
+
+    /// Resume a paused collection loop.
+    pub fn resume(&self) {
+        *self.state.lock().unwrap() = CollectionState::Running;
+    }
+
+    pub fn state(&self) -> CollectionState {
+        *self.state.lock().unwrap()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.handle.is_some() && self.running.load(Ordering::SeqCst)
+    }
+
+    /// Snapshot of every batch collected so far.
+    pub async fn buffered_results(&self) -> Vec<BatchReadResult> {
+        self.buffer.lock().await.clone()
+    }
+
+    /// Current buffer size, for a dashboard or to decide whether a
+    /// [`BufferMemoryLimit`] needs tightening.
+    pub async fn buffer_stats(&self) -> BufferStats {
+        super::estimate_buffer_stats(&self.buffer.lock().await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    fn batch(call: usize) -> BatchReadResult {
+        BatchReadResult {
+            at: DateTime::from_timestamp(call as i64, 0).unwrap(),
+            readings: vec![(AddressRange { start: 0, count: 1, slave_id: None }, vec![call as u16])],
+            actual_interval_ms: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn polls_periodically_and_buffers_every_batch() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut collector = DataCollector::new();
+
+        let calls_for_closure = Arc::clone(&calls);
+        collector.start(
+            5,
+            move || {
+                let call = calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(batch(call)) }
+            },
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert!(collector.is_running());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        collector.stop().await;
+
+        assert!(!collector.is_running());
+        let results = collector.buffered_results().await;
+        assert!(!results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn stop_preserves_already_collected_data() {
+        let mut collector = DataCollector::new();
+        collector.start(5, || async { Ok(batch(0)) }, None, None, None, None);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        collector.stop().await;
+
+        let before = collector.buffered_results().await.len();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        let after = collector.buffered_results().await.len();
+
+        assert_eq!(before, after);
+        assert!(before > 0);
+    }
+
+    #[tokio::test]
+    async fn stop_interrupts_an_in_flight_read_instead_of_waiting_out_its_timeout() {
+        let mut collector = DataCollector::new();
+
+        // A read far slower than the assertion below's bound: if stop()
+        // only set the running flag and waited for this cycle to finish
+        // naturally, the test would need seconds, not milliseconds.
+        collector.start(5, || async {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            Ok(batch(0))
+        }, None, None, None, None);
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let started = tokio::time::Instant::now();
+        collector.stop().await;
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(!collector.is_running());
+    }
+
+    #[tokio::test]
+    async fn a_failed_cycle_is_reported_to_the_event_sink_without_stopping_collection() {
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct RecordingSink {
+            batches: Mutex<usize>,
+            errors: Mutex<Vec<String>>,
+        }
+
+        impl CollectionEventSink for RecordingSink {
+            fn on_batch(&self, _batch: &BatchReadResult) {
+                *self.batches.lock().unwrap() += 1;
+            }
+
+            fn on_error(&self, user_friendly_message: &str) {
+                self.errors.lock().unwrap().push(user_friendly_message.to_string());
+            }
+        }
+
+        let sink = Arc::new(RecordingSink::default());
+        let mut collector = DataCollector::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_for_closure = Arc::clone(&calls);
+
+        collector.start(
+            5,
+            move || {
+                let call = calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if call == 0 {
+                        Err(AppError::InvalidConfig("读取超时".to_string()))
+                    } else {
+                        Ok(batch(call))
+                    }
+                }
+            },
+            Some(sink.clone() as Arc<dyn CollectionEventSink>),
+            None,
+            None,
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        collector.stop().await;
+
+        assert!(!sink.errors.lock().unwrap().is_empty());
+        assert!(*sink.batches.lock().unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn a_not_connected_failure_auto_pauses_instead_of_retrying_every_tick() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut collector = DataCollector::new();
+
+        let calls_for_closure = Arc::clone(&calls);
+        collector.start(
+            5,
+            move || {
+                calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async move { Err(AppError::NotConnected) }
+            },
+            None,
+            None,
+            None,
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(collector.state(), CollectionState::Paused);
+
+        let calls_when_paused = calls.load(Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        // Still ticking (pause skips the read, it doesn't stop the
+        // loop), but no longer attempting the read that keeps failing.
+        assert!(collector.is_running());
+        assert_eq!(calls.load(Ordering::SeqCst), calls_when_paused);
+
+        collector.stop().await;
+    }
+
+    #[tokio::test]
+    async fn pausing_keeps_the_loop_alive_but_skips_reads() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut collector = DataCollector::new();
+
+        let calls_for_closure = Arc::clone(&calls);
+        collector.start(
+            5,
+            move || {
+                let call = calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(batch(call)) }
+            },
+            None,
+            None,
+            None,
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        collector.pause();
+        assert_eq!(collector.state(), CollectionState::Paused);
+
+        let count_while_paused = collector.buffered_results().await.len();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(collector.buffered_results().await.len(), count_while_paused);
+        assert!(collector.is_running());
+
+        collector.resume();
+        assert_eq!(collector.state(), CollectionState::Running);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert!(collector.buffered_results().await.len() > count_while_paused);
+
+        collector.stop().await;
+    }
+
+    #[tokio::test]
+    async fn buffer_is_flushed_and_cleared_once_it_reaches_the_auto_flush_limit() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut collector = DataCollector::new();
+        let flushed: Arc<StdMutex<Vec<BatchReadResult>>> = Arc::new(StdMutex::new(Vec::new()));
+
+        let flushed_for_closure = Arc::clone(&flushed);
+        let writer: AutoFlushWriter = Arc::new(move |_path, data| {
+            flushed_for_closure.lock().unwrap().extend(data.iter().cloned());
+            Ok(())
+        });
+
+        let calls_for_closure = Arc::clone(&calls);
+        collector.start(
+            5,
+            move || {
+                let call = calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(batch(call)) }
+            },
+            None,
+            Some((
+                AutoFlushPolicy {
+                    max_buffer_size: 2,
+                    export_path: std::path::PathBuf::from("/tmp/unused.csv"),
+                },
+                writer,
+            )),
+            None,
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        collector.stop().await;
+
+        // The buffer never grows past the flush limit, and nothing
+        // collected is lost: it ends up either still buffered or
+        // already flushed out.
+        let still_buffered = collector.buffered_results().await.len();
+        assert!(still_buffered < 2);
+        assert!(!flushed.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_failed_flush_is_reported_but_does_not_stop_collection() {
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct RecordingSink {
+            errors: Mutex<Vec<String>>,
+        }
+
+        impl CollectionEventSink for RecordingSink {
+            fn on_batch(&self, _batch: &BatchReadResult) {}
+
+            fn on_error(&self, user_friendly_message: &str) {
+                self.errors.lock().unwrap().push(user_friendly_message.to_string());
+            }
+        }
+
+        let sink = Arc::new(RecordingSink::default());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut collector = DataCollector::new();
+
+        let writer: AutoFlushWriter =
+            Arc::new(|_path, _data| Err(AppError::InvalidConfig("磁盘已满".to_string())));
+
+        let calls_for_closure = Arc::clone(&calls);
+        collector.start(
+            5,
+            move || {
+                let call = calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(batch(call)) }
+            },
+            Some(sink.clone() as Arc<dyn CollectionEventSink>),
+            Some((
+                AutoFlushPolicy {
+                    max_buffer_size: 1,
+                    export_path: std::path::PathBuf::from("/tmp/unused.csv"),
+                },
+                writer,
+            )),
+            None,
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        collector.stop().await;
+
+        assert!(!sink.errors.lock().unwrap().is_empty());
+        assert!(!collector.buffered_results().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn buffer_stats_tracks_the_entry_count_as_batches_accumulate() {
+        let mut collector = DataCollector::new();
+        assert_eq!(collector.buffer_stats().await.entry_count, 0);
+
+        collector.start(5, || async { Ok(batch(0)) }, None, None, None, None);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        collector.stop().await;
+
+        let stats = collector.buffer_stats().await;
+        assert_eq!(stats.entry_count, collector.buffered_results().await.len());
+        assert!(stats.estimated_bytes > 0);
+    }
+
+    #[tokio::test]
+    async fn a_memory_limit_drops_the_oldest_batches_once_the_byte_budget_is_exceeded() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut collector = DataCollector::new();
+
+        let calls_for_closure = Arc::clone(&calls);
+        let budget = super::super::estimate_buffer_stats(&[batch(0), batch(1)]).estimated_bytes;
+        collector.start(
+            5,
+            move || {
+                let call = calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(batch(call)) }
+            },
+            None,
+            None,
+            Some((
+                super::super::BufferMemoryLimit {
+                    max_bytes: budget,
+                    policy: super::super::MemoryLimitPolicy::DropOldest,
+                    export_path: std::path::PathBuf::from("/tmp/unused.csv"),
+                },
+                Arc::new(|_path, _data| panic!("DropOldest should never invoke the writer")),
+            )),
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        collector.stop().await;
+
+        assert!(collector.buffer_stats().await.estimated_bytes <= budget);
+    }
+
+    #[tokio::test]
+    async fn every_batch_after_the_first_carries_the_elapsed_time_since_the_previous_tick() {
+        let mut collector = DataCollector::new();
+        collector.start(5, || async { Ok(batch(0)) }, None, None, None, None);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        collector.stop().await;
+
+        let results = collector.buffered_results().await;
+        assert!(results[0].actual_interval_ms.is_none());
+        assert!(results[1..].iter().all(|b| b.actual_interval_ms.is_some()));
+    }
+
+    #[tokio::test]
+    async fn a_slow_read_skips_its_missed_ticks_instead_of_queuing_them_under_the_skip_policy() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut collector = DataCollector::new();
+
+        // Each read takes far longer than the 5ms interval, so without
+        // `MissedTickBehavior::Skip` tokio would fire every missed tick
+        // back-to-back the instant this read returns.
+        let calls_for_closure = Arc::clone(&calls);
+        collector.start(
+            5,
+            move || {
+                let call = calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    tokio::time::sleep(Duration::from_millis(40)).await;
+                    Ok(batch(call))
+                }
+            },
+            None,
+            None,
+            None,
+            Some(MissedTickBehavior::Skip),
+        );
+
+        tokio::time::sleep(Duration::from_millis(130)).await;
+        collector.stop().await;
+
+        // ~130ms of wall time at one ~40ms-plus-interval read per cycle
+        // allows at most a handful of cycles; an unbounded burst of
+        // queued ticks would instead fire dozens in that window.
+        assert!(calls.load(Ordering::SeqCst) <= 4);
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use crate::modbus::AddressResult;
+
+/// Suppresses recording a sample whose value hasn't moved enough to
+/// matter, keeping export volume down for slowly-changing points. The
+/// first sample seen for a point is always kept, since there is nothing
+/// yet to compare it against.
+#[derive(Debug, Default)]
+pub struct DeadbandFilter {
+    deadband: Option<f64>,
+    last_recorded: HashMap<String, AddressResult>,
+}
+
+impl DeadbandFilter {
+    pub fn new(deadband: Option<f64>) -> Self {
+        Self {
+            deadband,
+            last_recorded: HashMap::new(),
+        }
+    }
+
+    /// Whether `result` for `point_id` should be recorded: always true
+    /// for a point's first sample, or when no deadband is configured.
+    /// Otherwise, numeric points (`raw_value: Some`) are compared by
+    /// `|new - old| > deadband`; bit/string points (`raw_value: None`)
+    /// are compared by inequality of `parsed_value`. Comparisons are
+    /// against the last *recorded* sample, not the last one seen, so
+    /// slow drift within the deadband still eventually triggers a write
+    /// once it has moved far enough from the last reported point.
+    pub fn should_record(&mut self, point_id: &str, result: &AddressResult) -> bool {
+        let keep = match (self.deadband, self.last_recorded.get(point_id)) {
+            (_, None) => true,
+            (None, Some(_)) => true,
+            (Some(deadband), Some(previous)) => match (result.raw_value, previous.raw_value) {
+                (Some(new), Some(old)) => (new - old).abs() > deadband,
+                _ => result.parsed_value != previous.parsed_value,
+            },
+        };
+
+        if keep {
+            self.last_recorded.insert(point_id.to_string(), result.clone());
+        }
+        keep
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modbus::Quality;
+
+    fn numeric_result(value: f64) -> AddressResult {
+        AddressResult {
+            parsed_value: value.to_string(),
+            raw_value: Some(value),
+            saturated: false,
+            quality: Quality::Good,
+            label: None,
+            unit: None,
+            raw_bytes: None,
+        }
+    }
+
+    fn string_result(value: &str) -> AddressResult {
+        AddressResult {
+            parsed_value: value.to_string(),
+            raw_value: None,
+            saturated: false,
+            quality: Quality::Good,
+            label: None,
+            unit: None,
+            raw_bytes: None,
+        }
+    }
+
+    #[test]
+    fn the_first_sample_for_a_point_is_always_recorded() {
+        let mut filter = DeadbandFilter::new(Some(1.0));
+        assert!(filter.should_record("temp", &numeric_result(10.0)));
+    }
+
+    #[test]
+    fn without_a_configured_deadband_every_sample_is_recorded() {
+        let mut filter = DeadbandFilter::new(None);
+        assert!(filter.should_record("temp", &numeric_result(10.0)));
+        assert!(filter.should_record("temp", &numeric_result(10.01)));
+    }
+
+    #[test]
+    fn a_change_within_the_deadband_is_suppressed() {
+        let mut filter = DeadbandFilter::new(Some(0.5));
+        assert!(filter.should_record("temp", &numeric_result(10.0)));
+        assert!(!filter.should_record("temp", &numeric_result(10.2)));
+    }
+
+    #[test]
+    fn a_change_beyond_the_deadband_is_recorded() {
+        let mut filter = DeadbandFilter::new(Some(0.5));
+        assert!(filter.should_record("temp", &numeric_result(10.0)));
+        assert!(filter.should_record("temp", &numeric_result(10.6)));
+    }
+
+    #[test]
+    fn string_and_bit_points_compare_by_inequality_rather_than_deadband() {
+        let mut filter = DeadbandFilter::new(Some(5.0));
+        assert!(filter.should_record("status", &string_result("OK")));
+        assert!(!filter.should_record("status", &string_result("OK")));
+        assert!(filter.should_record("status", &string_result("FAULT")));
+    }
+
+    #[test]
+    fn comparisons_use_the_last_recorded_value_not_the_last_seen_one() {
+        let mut filter = DeadbandFilter::new(Some(1.0));
+        assert!(filter.should_record("temp", &numeric_result(10.0)));
+        assert!(!filter.should_record("temp", &numeric_result(10.5)));
+        // Still within 1.0 of the last *recorded* sample (10.0), even
+        // though it moved further from the last *seen* one (10.5).
+        assert!(!filter.should_record("temp", &numeric_result(10.9)));
+        assert!(filter.should_record("temp", &numeric_result(11.1)));
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+struct LastSample {
+    value: f64,
+    at: DateTime<Utc>,
+}
+
+/// Computes the rate of change (per second) of each point between
+/// consecutive samples, so a dashboard can show a derived "speed" point
+/// alongside the raw reading.
+#[derive(Default)]
+pub struct RateOfChangeTracker {
+    last: HashMap<String, LastSample>,
+}
+
+impl RateOfChangeTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a new sample for `point_id`. Returns `None` for the first
+    /// sample of a point (there is nothing to compare against yet) or
+    /// when two samples share the same timestamp.
+    pub fn record(&mut self, point_id: &str, value: f64, at: DateTime<Utc>) -> Option<f64> {
+        let rate = self.last.get(point_id).and_then(|prev| {
+            let dt = (at - prev.at).num_milliseconds() as f64 / 1000.0;
+            if dt == 0.0 {
+                None
+            } else {
+                Some((value - prev.value) / dt)
+            }
+        });
+
+        self.last
+            .insert(point_id.to_string(), LastSample { value, at });
+
+        rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn first_sample_has_no_rate() {
+        let mut tracker = RateOfChangeTracker::new();
+        assert_eq!(tracker.record("temp", 10.0, at(0)), None);
+    }
+
+    #[test]
+    fn rate_is_delta_value_over_delta_seconds() {
+        let mut tracker = RateOfChangeTracker::new();
+        tracker.record("temp", 10.0, at(0));
+        let rate = tracker.record("temp", 20.0, at(2)).unwrap();
+        assert_eq!(rate, 5.0);
+    }
+}
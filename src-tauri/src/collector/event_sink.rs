@@ -0,0 +1,51 @@
+use super::BatchReadResult;
+
+/// Receives live updates from a running [`super::DataCollector`] so a
+/// caller can push them onward (e.g. as Tauri events to the frontend)
+/// without the collector needing to know anything about how they are
+/// delivered.
+pub trait CollectionEventSink: Send + Sync {
+    /// Called once per successfully collected batch.
+    fn on_batch(&self, batch: &BatchReadResult);
+    /// Called when a collection cycle fails. `user_friendly_message` is
+    /// suitable for displaying directly, without exposing internals.
+    fn on_error(&self, user_friendly_message: &str);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingSink {
+        batches: Mutex<Vec<BatchReadResult>>,
+        errors: Mutex<Vec<String>>,
+    }
+
+    impl CollectionEventSink for RecordingSink {
+        fn on_batch(&self, batch: &BatchReadResult) {
+            self.batches.lock().unwrap().push(batch.clone());
+        }
+
+        fn on_error(&self, user_friendly_message: &str) {
+            self.errors.lock().unwrap().push(user_friendly_message.to_string());
+        }
+    }
+
+    #[test]
+    fn records_batches_and_errors_independently() {
+        let sink = RecordingSink::default();
+        let batch = BatchReadResult {
+            at: chrono::DateTime::from_timestamp(0, 0).unwrap(),
+            readings: vec![],
+            actual_interval_ms: None,
+        };
+
+        sink.on_batch(&batch);
+        sink.on_error("设备无响应");
+
+        assert_eq!(sink.batches.lock().unwrap().len(), 1);
+        assert_eq!(sink.errors.lock().unwrap().as_slice(), ["设备无响应"]);
+    }
+}
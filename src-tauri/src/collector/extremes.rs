@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+/// The highest and lowest value seen for a point since the tracker was
+/// last reset, along with when each occurred.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Extreme {
+    pub max: f64,
+    pub max_at: DateTime<Utc>,
+    pub min: f64,
+    pub min_at: DateTime<Utc>,
+}
+
+/// Tracks the session-level max/min of every collected point so the
+/// dashboard can show "this session's max/min" without re-scanning the
+/// full history on every sample.
+#[derive(Debug, Default)]
+pub struct ExtremesTracker {
+    points: HashMap<String, Extreme>,
+}
+
+impl ExtremesTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold a newly collected sample into the running extremes for `point_id`.
+    pub fn record(&mut self, point_id: &str, value: f64, at: DateTime<Utc>) {
+        match self.points.get_mut(point_id) {
+            Some(extreme) => {
+                if value > extreme.max {
+                    extreme.max = value;
+                    extreme.max_at = at;
+                }
+                if value < extreme.min {
+                    extreme.min = value;
+                    extreme.min_at = at;
+                }
+            }
+            None => {
+                self.points.insert(
+                    point_id.to_string(),
+                    Extreme {
+                        max: value,
+                        max_at: at,
+                        min: value,
+                        min_at: at,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Current session max/min for every point seen since the last reset.
+    pub fn get_session_extremes(&self) -> &HashMap<String, Extreme> {
+        &self.points
+    }
+
+    /// Clear all tracked extremes so statistics start fresh.
+    pub fn reset_extremes(&mut self) {
+        self.points.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn tracks_max_and_min_across_samples() {
+        let mut tracker = ExtremesTracker::new();
+
+        tracker.record("temp", 10.0, at(0));
+        tracker.record("temp", 25.0, at(1));
+        tracker.record("temp", 5.0, at(2));
+
+        let extreme = tracker.get_session_extremes()["temp"];
+        assert_eq!(extreme.max, 25.0);
+        assert_eq!(extreme.max_at, at(1));
+        assert_eq!(extreme.min, 5.0);
+        assert_eq!(extreme.min_at, at(2));
+    }
+
+    #[test]
+    fn reset_clears_all_tracked_points() {
+        let mut tracker = ExtremesTracker::new();
+        tracker.record("temp", 10.0, at(0));
+
+        tracker.reset_extremes();
+
+        assert!(tracker.get_session_extremes().is_empty());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::error::AppError;
+
+/// Gate a collection task's startup behind a connectivity check: running
+/// `probe` before spawning the background loop avoids starting a task
+/// that will immediately fail every read. `probe` returns `true` when
+/// the device is reachable.
+pub fn ensure_connectivity<F>(probe: F) -> Result<(), AppError>
+where
+    F: FnOnce() -> bool,
+{
+    if probe() {
+        Ok(())
+    } else {
+        Err(AppError::InvalidConfig(
+            "设备连通性检查失败，无法启动采集".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreachable_device_blocks_startup() {
+        let result = ensure_connectivity(|| false);
+        assert!(matches!(result, Err(AppError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn reachable_device_allows_startup() {
+        assert!(ensure_connectivity(|| true).is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+use std::path::PathBuf;
+
+use crate::error::AppError;
+
+/// A single register write to perform before a collection session
+/// starts, e.g. to "unlock" a device's acquisition mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteOp {
+    pub address: u16,
+    pub value: u16,
+}
+
+/// Configuration for a collection session, including the write sequence
+/// that must succeed before the session is allowed to start and, for
+/// long-running sessions, where to automatically flush the in-memory
+/// buffer once it grows too large.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CollectionConfig {
+    pub init_writes: Vec<WriteOp>,
+    /// Number of buffered batches that triggers an automatic flush to
+    /// `auto_export_path`. `None` means the buffer is never flushed
+    /// automatically.
+    pub max_buffer_size: Option<usize>,
+    pub auto_export_path: Option<PathBuf>,
+}
+
+/// Run `config.init_writes` in order via `write_register`, stopping at
+/// (and returning) the first failure so a session never starts with
+/// only part of its initialization sequence applied.
+pub fn run_init_writes<F>(config: &CollectionConfig, mut write_register: F) -> Result<(), AppError>
+where
+    F: FnMut(u16, u16) -> Result<(), AppError>,
+{
+    for op in &config.init_writes {
+        write_register(op.address, op.value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn executes_every_write_in_order() {
+        let config = CollectionConfig {
+            init_writes: vec![
+                WriteOp {
+                    address: 100,
+                    value: 1,
+                },
+                WriteOp {
+                    address: 101,
+                    value: 2,
+                },
+            ],
+            ..Default::default()
+        };
+        let mut applied = Vec::new();
+
+        let result = run_init_writes(&config, |address, value| {
+            applied.push((address, value));
+            Ok(())
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(applied, vec![(100, 1), (101, 2)]);
+    }
+
+    #[test]
+    fn a_failing_write_stops_the_sequence_and_is_returned() {
+        let config = CollectionConfig {
+            init_writes: vec![
+                WriteOp {
+                    address: 100,
+                    value: 1,
+                },
+                WriteOp {
+                    address: 101,
+                    value: 2,
+                },
+            ],
+            ..Default::default()
+        };
+        let mut applied = Vec::new();
+
+        let result = run_init_writes(&config, |address, value| {
+            applied.push((address, value));
+            if address == 100 {
+                Err(AppError::InvalidConfig("写入失败".to_string()))
+            } else {
+                Ok(())
+            }
+        });
+
+        assert!(result.is_err());
+        assert_eq!(applied, vec![(100, 1)]);
+    }
+
+    #[test]
+    fn empty_sequence_succeeds_without_calling_the_writer() {
+        let config = CollectionConfig::default();
+        let result = run_init_writes(&config, |_, _| {
+            panic!("writer should not be called for an empty init sequence")
+        });
+        assert!(result.is_ok());
+    }
+}
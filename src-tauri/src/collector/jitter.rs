@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+
+/// Summary statistics over the sampling jitter observed so far: how far
+/// actual sample intervals drifted from the configured collection
+/// interval, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JitterStats {
+    pub mean_ms: f64,
+    pub max_ms: i64,
+    pub stddev_ms: f64,
+}
+
+/// Tracks the gap between consecutive collector ticks against the
+/// configured interval, to surface how much the real sampling cadence
+/// drifts under load.
+pub struct JitterTracker {
+    expected_interval_ms: i64,
+    last_at: Option<DateTime<Utc>>,
+    deviations_ms: Vec<i64>,
+}
+
+impl JitterTracker {
+    pub fn new(expected_interval_ms: i64) -> Self {
+        Self {
+            expected_interval_ms,
+            last_at: None,
+            deviations_ms: Vec::new(),
+        }
+    }
+
+    /// Record a new sample timestamp. Returns the absolute deviation (in
+    /// ms) from the expected interval, or `None` for the first sample.
+    pub fn record(&mut self, at: DateTime<Utc>) -> Option<i64> {
+        let deviation = self.last_at.map(|last| {
+            let actual = (at - last).num_milliseconds();
+            (actual - self.expected_interval_ms).abs()
+        });
+
+        if let Some(deviation) = deviation {
+            self.deviations_ms.push(deviation);
+        }
+        self.last_at = Some(at);
+        deviation
+    }
+
+    pub fn stats(&self) -> JitterStats {
+        if self.deviations_ms.is_empty() {
+            return JitterStats {
+                mean_ms: 0.0,
+                max_ms: 0,
+                stddev_ms: 0.0,
+            };
+        }
+
+        let n = self.deviations_ms.len() as f64;
+        let mean = self.deviations_ms.iter().sum::<i64>() as f64 / n;
+        let variance = self
+            .deviations_ms
+            .iter()
+            .map(|d| {
+                let diff = *d as f64 - mean;
+                diff * diff
+            })
+            .sum::<f64>()
+            / n;
+
+        JitterStats {
+            mean_ms: mean,
+            max_ms: *self.deviations_ms.iter().max().unwrap(),
+            stddev_ms: variance.sqrt(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(ms: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(ms).unwrap()
+    }
+
+    #[test]
+    fn tracks_deviation_from_expected_interval() {
+        let mut tracker = JitterTracker::new(100);
+        assert_eq!(tracker.record(at(0)), None);
+        assert_eq!(tracker.record(at(110)), Some(10));
+        assert_eq!(tracker.record(at(195)), Some(15));
+
+        let stats = tracker.stats();
+        assert_eq!(stats.max_ms, 15);
+        assert_eq!(stats.mean_ms, 12.5);
+    }
+}
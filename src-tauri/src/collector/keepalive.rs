@@ -0,0 +1,192 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+
+use tokio::task::JoinHandle;
+use tokio::time::{interval, Duration};
+
+use crate::error::AppError;
+
+use super::{CollectionEventSink, CollectionState};
+
+/// Periodically probes the connection independently of the main polling
+/// loop, so an idle connection that a switch or device silently drops
+/// is detected even when no ordinary read is due for a while. `probe`
+/// is expected to share the same connection mutex as normal reads
+/// (e.g. via [`crate::modbus::ConnectionLock`]) so the heartbeat and a
+/// real read never race on the wire.
+pub struct KeepaliveTask {
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Default for KeepaliveTask {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeepaliveTask {
+    pub fn new() -> Self {
+        Self {
+            running: Arc::new(AtomicBool::new(false)),
+            handle: None,
+        }
+    }
+
+    /// Start probing every `interval_ms` milliseconds via `probe`. A
+    /// failed probe marks `state` as [`CollectionState::Error`], reports
+    /// it to `events`, and attempts to recover via `reconnect`; a
+    /// successful reconnect restores `state` to
+    /// [`CollectionState::Running`]. A no-op if already running.
+    pub fn start<P, PFut, R, RFut>(
+        &mut self,
+        interval_ms: u64,
+        probe: P,
+        reconnect: R,
+        state: Arc<StdMutex<CollectionState>>,
+        events: Option<Arc<dyn CollectionEventSink>>,
+    ) where
+        P: Fn() -> PFut + Send + Sync + 'static,
+        PFut: Future<Output = Result<(), AppError>> + Send + 'static,
+        R: Fn() -> RFut + Send + Sync + 'static,
+        RFut: Future<Output = Result<(), AppError>> + Send + 'static,
+    {
+        if self.handle.is_some() {
+            return;
+        }
+
+        self.running.store(true, Ordering::SeqCst);
+        let running = Arc::clone(&self.running);
+
+        self.handle = Some(tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_millis(interval_ms));
+            while running.load(Ordering::SeqCst) {
+                ticker.tick().await;
+                if !running.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                if let Err(err) = probe().await {
+                    *state.lock().unwrap() = CollectionState::Error;
+                    if let Some(sink) = &events {
+                        sink.on_error(&format!("心跳探测失败：{err}"));
+                    }
+
+                    match reconnect().await {
+                        Ok(()) => *state.lock().unwrap() = CollectionState::Running,
+                        Err(err) => {
+                            if let Some(sink) = &events {
+                                sink.on_error(&format!("心跳重连失败：{err}"));
+                            }
+                        }
+                    }
+                }
+            }
+        }));
+    }
+
+    /// Cancel the heartbeat loop, if running, and wait for it to wind down.
+    pub async fn stop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.handle.is_some() && self.running.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[tokio::test]
+    async fn a_healthy_connection_never_touches_state_or_reconnect() {
+        let state = Arc::new(StdMutex::new(CollectionState::Running));
+        let mut task = KeepaliveTask::new();
+
+        task.start(
+            5,
+            || async { Ok(()) },
+            || async { panic!("reconnect should not run for a healthy connection") },
+            Arc::clone(&state),
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        task.stop().await;
+
+        assert_eq!(*state.lock().unwrap(), CollectionState::Running);
+    }
+
+    #[tokio::test]
+    async fn a_failed_probe_marks_error_then_recovers_via_reconnect() {
+        let state = Arc::new(StdMutex::new(CollectionState::Running));
+        let probe_calls = Arc::new(AtomicUsize::new(0));
+        let mut task = KeepaliveTask::new();
+
+        let probe_calls_for_closure = Arc::clone(&probe_calls);
+        task.start(
+            5,
+            move || {
+                let call = probe_calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if call == 0 {
+                        Err(AppError::Io(std::io::Error::other("连接断开")))
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+            || async { Ok(()) },
+            Arc::clone(&state),
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        task.stop().await;
+
+        assert_eq!(*state.lock().unwrap(), CollectionState::Running);
+    }
+
+    #[tokio::test]
+    async fn a_failed_reconnect_is_reported_and_leaves_the_state_as_error() {
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct RecordingSink {
+            errors: Mutex<Vec<String>>,
+        }
+
+        impl CollectionEventSink for RecordingSink {
+            fn on_batch(&self, _batch: &super::super::BatchReadResult) {}
+
+            fn on_error(&self, user_friendly_message: &str) {
+                self.errors.lock().unwrap().push(user_friendly_message.to_string());
+            }
+        }
+
+        let sink = Arc::new(RecordingSink::default());
+        let state = Arc::new(StdMutex::new(CollectionState::Running));
+        let mut task = KeepaliveTask::new();
+
+        task.start(
+            5,
+            || async { Err(AppError::Io(std::io::Error::other("连接断开"))) },
+            || async { Err(AppError::Io(std::io::Error::other("重连失败"))) },
+            Arc::clone(&state),
+            Some(sink.clone() as Arc<dyn CollectionEventSink>),
+        );
+
+        tokio::time::sleep(Duration::from_millis(15)).await;
+        task.stop().await;
+
+        assert_eq!(*state.lock().unwrap(), CollectionState::Error);
+        assert!(sink.errors.lock().unwrap().len() >= 2);
+    }
+}
@@ -0,0 +1,43 @@
+//! The data collection engine: turns a configured set of address ranges
+//! into a running background acquisition session and the derived
+//! statistics the dashboard wants to show about it.
+
+mod auto_flush;
+mod buffer_memory;
+mod clock_offset;
+mod collection_state;
+mod data_collector;
+mod deadband;
+mod derivative;
+mod event_sink;
+mod extremes;
+mod gate;
+mod init_writes;
+mod jitter;
+mod keepalive;
+mod registry;
+mod sink;
+mod statistics;
+mod type_downgrade;
+mod warmup;
+mod window_stats;
+
+pub use auto_flush::{append_batches_to_csv, maybe_flush, AutoFlushPolicy};
+pub use buffer_memory::{enforce_memory_limit, estimate_buffer_stats, BufferMemoryLimit, BufferStats, MemoryLimitPolicy};
+pub use clock_offset::ClockOffset;
+pub use collection_state::CollectionState;
+pub use data_collector::{AutoFlushWriter, BatchReadResult, DataCollector};
+pub use deadband::DeadbandFilter;
+pub use derivative::RateOfChangeTracker;
+pub use event_sink::CollectionEventSink;
+pub use extremes::{Extreme, ExtremesTracker};
+pub use gate::ensure_connectivity;
+pub use init_writes::{run_init_writes, CollectionConfig, WriteOp};
+pub use jitter::{JitterStats, JitterTracker};
+pub use keepalive::KeepaliveTask;
+pub use registry::CollectorRegistry;
+pub use sink::{DataSink, FanOutSink};
+pub use statistics::{compute_statistics, AddressStats};
+pub use type_downgrade::{AutoDowngradePolicy, DowngradeTracker};
+pub use warmup::WarmupFilter;
+pub use window_stats::{WindowStats, WindowStatsTracker};
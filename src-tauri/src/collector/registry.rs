@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::time::MissedTickBehavior;
+
+use crate::error::AppError;
+
+use super::{AutoFlushPolicy, AutoFlushWriter, BatchReadResult, BufferMemoryLimit, CollectionEventSink, DataCollector};
+
+/// Keeps a [`DataCollector`] per name, so independent acquisition
+/// sessions (different devices, different configs, different outputs)
+/// can run in the same process without interfering with each other.
+#[derive(Default)]
+pub struct CollectorRegistry {
+    collectors: HashMap<String, DataCollector>,
+}
+
+impl CollectorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start the named collector, creating it first if this is the
+    /// first time `name` is used. A no-op if it is already running.
+    #[allow(clippy::too_many_arguments)]
+    pub fn start<F, Fut>(
+        &mut self,
+        name: &str,
+        interval_ms: u64,
+        read_ranges_detailed: F,
+        events: Option<Arc<dyn CollectionEventSink>>,
+        auto_flush: Option<(AutoFlushPolicy, AutoFlushWriter)>,
+        memory_limit: Option<(BufferMemoryLimit, AutoFlushWriter)>,
+        missed_tick_policy: Option<MissedTickBehavior>,
+    ) where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<BatchReadResult, AppError>> + Send + 'static,
+    {
+        self.collectors.entry(name.to_string()).or_default().start(
+            interval_ms,
+            read_ranges_detailed,
+            events,
+            auto_flush,
+            memory_limit,
+            missed_tick_policy,
+        );
+    }
+
+    /// Current buffer size for the named collector, or `None` for an
+    /// unknown name.
+    pub async fn buffer_stats(&self, name: &str) -> Option<super::BufferStats> {
+        match self.collectors.get(name) {
+            Some(collector) => Some(collector.buffer_stats().await),
+            None => None,
+        }
+    }
+
+    /// Stop the named collector, if it exists. A no-op for an unknown name.
+    pub async fn stop(&mut self, name: &str) {
+        if let Some(collector) = self.collectors.get_mut(name) {
+            collector.stop().await;
+        }
+    }
+
+    pub fn is_running(&self, name: &str) -> bool {
+        self.collectors.get(name).is_some_and(DataCollector::is_running)
+    }
+
+    /// Drop the named collector's entry entirely, returning it so the
+    /// caller can e.g. flush it one last time. Does not stop it first.
+    pub fn remove(&mut self, name: &str) -> Option<DataCollector> {
+        self.collectors.remove(name)
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.collectors.keys().map(String::as_str).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::time::Duration;
+
+    fn batch() -> BatchReadResult {
+        BatchReadResult {
+            at: DateTime::from_timestamp(0, 0).unwrap(),
+            readings: vec![],
+            actual_interval_ms: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn two_named_collectors_start_and_stop_independently() {
+        let mut registry = CollectorRegistry::new();
+
+        registry.start("device-a", 5, || async { Ok(batch()) }, None, None, None, None);
+        registry.start("device-b", 5, || async { Ok(batch()) }, None, None, None, None);
+
+        assert!(registry.is_running("device-a"));
+        assert!(registry.is_running("device-b"));
+
+        registry.stop("device-a").await;
+
+        assert!(!registry.is_running("device-a"));
+        assert!(registry.is_running("device-b"));
+
+        registry.stop("device-b").await;
+        assert!(!registry.is_running("device-b"));
+    }
+
+    #[tokio::test]
+    async fn each_collector_buffers_its_own_batches() {
+        let mut registry = CollectorRegistry::new();
+        let a_calls = Arc::new(AtomicUsize::new(0));
+        let b_calls = Arc::new(AtomicUsize::new(0));
+
+        let a_calls_for_closure = Arc::clone(&a_calls);
+        registry.start(
+            "a",
+            5,
+            move || {
+                a_calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async { Ok(batch()) }
+            },
+            None,
+            None,
+            None,
+            None,
+        );
+        let b_calls_for_closure = Arc::clone(&b_calls);
+        registry.start(
+            "b",
+            50_000,
+            move || {
+                b_calls_for_closure.fetch_add(1, Ordering::SeqCst);
+                async { Ok(batch()) }
+            },
+            None,
+            None,
+            None,
+            None,
+        );
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        registry.stop("a").await;
+        registry.stop("b").await;
+
+        // "a" polls every 5ms, "b" every 50s: "a" accumulates many
+        // batches in this window while "b" only fires its one
+        // immediate first tick.
+        assert!(a_calls.load(Ordering::SeqCst) > b_calls.load(Ordering::SeqCst));
+        assert_eq!(b_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn stopping_an_unknown_name_is_a_no_op() {
+        let mut registry = CollectorRegistry::new();
+        registry.stop("missing").await;
+        assert!(!registry.is_running("missing"));
+        assert!(registry.names().is_empty());
+    }
+}
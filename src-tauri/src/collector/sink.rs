@@ -0,0 +1,85 @@
+use std::io;
+
+/// A single destination a collected record can be written to (a CSV
+/// file, a network socket, ...).
+pub trait DataSink {
+    fn write_record(&mut self, record: &str) -> io::Result<()>;
+}
+
+/// Fans a single collected record out to multiple [`DataSink`]s. A
+/// failure writing to one sink does not stop the others from receiving
+/// the record; every failure is collected and returned instead.
+#[derive(Default)]
+pub struct FanOutSink {
+    sinks: Vec<Box<dyn DataSink>>,
+}
+
+impl FanOutSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_sink(&mut self, sink: Box<dyn DataSink>) {
+        self.sinks.push(sink);
+    }
+
+    /// Write `record` to every registered sink, returning the index and
+    /// error for each sink that failed.
+    pub fn write_record(&mut self, record: &str) -> Vec<(usize, io::Error)> {
+        self.sinks
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, sink)| sink.write_record(record).err().map(|err| (index, err)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct RecordingSink {
+        records: Vec<String>,
+    }
+
+    impl DataSink for RecordingSink {
+        fn write_record(&mut self, record: &str) -> io::Result<()> {
+            self.records.push(record.to_string());
+            Ok(())
+        }
+    }
+
+    struct FailingSink;
+
+    impl DataSink for FailingSink {
+        fn write_record(&mut self, _record: &str) -> io::Result<()> {
+            Err(io::Error::other("disk full"))
+        }
+    }
+
+    #[test]
+    fn one_failing_sink_does_not_block_the_others() {
+        let mut fan_out = FanOutSink::new();
+        fan_out.add_sink(Box::new(RecordingSink {
+            records: Vec::new(),
+        }));
+        fan_out.add_sink(Box::new(FailingSink));
+
+        let failures = fan_out.write_record("1,2,3");
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, 1);
+    }
+
+    #[test]
+    fn no_failures_are_reported_when_every_sink_succeeds() {
+        let mut fan_out = FanOutSink::new();
+        fan_out.add_sink(Box::new(RecordingSink {
+            records: Vec::new(),
+        }));
+
+        let failures = fan_out.write_record("1,2,3");
+
+        assert!(failures.is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use super::BatchReadResult;
+
+/// Aggregate statistics for one register address across every sample in
+/// a set of collected batches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AddressStats {
+    pub min: u16,
+    pub max: u16,
+    pub avg: f64,
+    pub count: usize,
+    pub last: u16,
+}
+
+/// Fold every batch's raw register readings into per-address
+/// min/max/avg/count/last statistics, keyed by absolute register address
+/// (`range.start` plus the register's offset within the range). Register
+/// values are always numeric u16s — the Modbus wire protocol has no
+/// string/bool register type; a non-numeric display value (e.g.
+/// `DataType::String`) only appears downstream, once
+/// [`crate::modbus::create_address_result`] decodes a specific point's
+/// registers — so there is no "can't parse, skip it" case to apply at
+/// this raw level. `last` reflects the value from the chronologically
+/// last batch the address appeared in, relying on `batches` being in
+/// collection order the way [`super::DataCollector`]'s buffer always is.
+/// An empty `batches` slice returns an empty map.
+pub fn compute_statistics(batches: &[BatchReadResult]) -> HashMap<u16, AddressStats> {
+    let mut running: HashMap<u16, (u16, u16, f64, usize, u16)> = HashMap::new();
+
+    for batch in batches {
+        for (range, registers) in &batch.readings {
+            for (offset, &value) in registers.iter().enumerate() {
+                let address = range.start.wrapping_add(offset as u16);
+                running
+                    .entry(address)
+                    .and_modify(|(min, max, sum, count, last)| {
+                        *min = (*min).min(value);
+                        *max = (*max).max(value);
+                        *sum += value as f64;
+                        *count += 1;
+                        *last = value;
+                    })
+                    .or_insert((value, value, value as f64, 1, value));
+            }
+        }
+    }
+
+    running
+        .into_iter()
+        .map(|(address, (min, max, sum, count, last))| {
+            (
+                address,
+                AddressStats {
+                    min,
+                    max,
+                    avg: sum / count as f64,
+                    count,
+                    last,
+                },
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modbus::AddressRange;
+    use chrono::DateTime;
+
+    fn range(start: u16, count: u16) -> AddressRange {
+        AddressRange { start, count, slave_id: None }
+    }
+
+    fn batch(readings: Vec<(AddressRange, Vec<u16>)>) -> BatchReadResult {
+        BatchReadResult { at: DateTime::from_timestamp(0, 0).unwrap(), readings, actual_interval_ms: None }
+    }
+
+    #[test]
+    fn an_empty_batch_slice_yields_an_empty_map() {
+        assert!(compute_statistics(&[]).is_empty());
+    }
+
+    #[test]
+    fn min_max_avg_count_and_last_are_tracked_per_address_across_batches() {
+        let batches = vec![
+            batch(vec![(range(0, 1), vec![10])]),
+            batch(vec![(range(0, 1), vec![30])]),
+            batch(vec![(range(0, 1), vec![20])]),
+        ];
+
+        let stats = compute_statistics(&batches);
+        let addr0 = stats[&0];
+
+        assert_eq!(addr0.min, 10);
+        assert_eq!(addr0.max, 30);
+        assert_eq!(addr0.avg, 20.0);
+        assert_eq!(addr0.count, 3);
+        assert_eq!(addr0.last, 20);
+    }
+
+    #[test]
+    fn each_register_within_a_multi_register_range_gets_its_own_entry() {
+        let batches = vec![batch(vec![(range(100, 3), vec![1, 2, 3])])];
+
+        let stats = compute_statistics(&batches);
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats[&100].last, 1);
+        assert_eq!(stats[&101].last, 2);
+        assert_eq!(stats[&102].last, 3);
+    }
+
+    #[test]
+    fn addresses_from_different_ranges_are_tracked_independently() {
+        let batches = vec![batch(vec![(range(0, 1), vec![5]), (range(50, 1), vec![99])])];
+
+        let stats = compute_statistics(&batches);
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[&0].last, 5);
+        assert_eq!(stats[&50].last, 99);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::error::AppError;
+
+/// Whether, and how eagerly, a point's read should fall back to a more
+/// permissive data type once decoding keeps failing — e.g. a point
+/// configured as float32 that turns out to actually be uint16.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoDowngradePolicy {
+    pub enabled: bool,
+    pub consecutive_failures_before_downgrade: u32,
+}
+
+/// Tracks consecutive decode failures for a single point and decides
+/// when to stop trying the configured ("primary") data type and switch
+/// permanently to a fallback one, so a misconfigured `data_type` doesn't
+/// block acquisition indefinitely once repeated failures make the real
+/// shape of the device's data clear.
+#[derive(Debug, Default)]
+pub struct DowngradeTracker {
+    consecutive_failures: u32,
+    downgraded: bool,
+}
+
+impl DowngradeTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_downgraded(&self) -> bool {
+        self.downgraded
+    }
+
+    /// Attempt `primary`. If it fails and `policy.enabled`, count the
+    /// failure and switch to `fallback` for good once
+    /// `policy.consecutive_failures_before_downgrade` consecutive
+    /// failures have been seen, including this one. Once downgraded,
+    /// later calls go straight to `fallback` without retrying `primary`.
+    /// A successful `primary` read resets the failure count.
+    pub fn decode<T>(
+        &mut self,
+        policy: &AutoDowngradePolicy,
+        primary: impl FnOnce() -> Result<T, AppError>,
+        fallback: impl FnOnce() -> Result<T, AppError>,
+    ) -> Result<T, AppError> {
+        if self.downgraded {
+            return fallback();
+        }
+
+        match primary() {
+            Ok(value) => {
+                self.consecutive_failures = 0;
+                Ok(value)
+            }
+            Err(err) if policy.enabled => {
+                self.consecutive_failures += 1;
+                if self.consecutive_failures >= policy.consecutive_failures_before_downgrade {
+                    self.downgraded = true;
+                    fallback()
+                } else {
+                    Err(err)
+                }
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(consecutive_failures_before_downgrade: u32) -> AutoDowngradePolicy {
+        AutoDowngradePolicy {
+            enabled: true,
+            consecutive_failures_before_downgrade,
+        }
+    }
+
+    fn fail<T>() -> Result<T, AppError> {
+        Err(AppError::InvalidConfig("float32 解析失败".to_string()))
+    }
+
+    #[test]
+    fn a_disabled_policy_never_downgrades_no_matter_how_many_failures() {
+        let mut tracker = DowngradeTracker::new();
+        let disabled = AutoDowngradePolicy {
+            enabled: false,
+            consecutive_failures_before_downgrade: 1,
+        };
+
+        for _ in 0..5 {
+            let result = tracker.decode(&disabled, fail::<u16>, || panic!("fallback should never run"));
+            assert!(result.is_err());
+        }
+        assert!(!tracker.is_downgraded());
+    }
+
+    #[test]
+    fn enough_consecutive_failures_triggers_a_downgrade_that_produces_data() {
+        let mut tracker = DowngradeTracker::new();
+        let policy = policy(3);
+
+        assert!(tracker.decode(&policy, fail::<u16>, || Ok(1)).is_err());
+        assert!(tracker.decode(&policy, fail::<u16>, || Ok(1)).is_err());
+        let result = tracker.decode(&policy, fail::<u16>, || Ok(42));
+
+        assert_eq!(result.unwrap(), 42);
+        assert!(tracker.is_downgraded());
+    }
+
+    #[test]
+    fn once_downgraded_the_primary_decoder_is_never_called_again() {
+        let mut tracker = DowngradeTracker::new();
+        let policy = policy(1);
+        tracker.decode(&policy, fail::<u16>, || Ok(42)).unwrap();
+
+        let result = tracker.decode(&policy, || panic!("primary should not run once downgraded"), || Ok(7));
+
+        assert_eq!(result.unwrap(), 7);
+    }
+
+    #[test]
+    fn a_successful_read_resets_the_failure_count_so_downgrade_never_triggers() {
+        let mut tracker = DowngradeTracker::new();
+        let policy = policy(2);
+
+        assert!(tracker.decode(&policy, fail::<u16>, || panic!("not yet")).is_err());
+        assert_eq!(tracker.decode(&policy, || Ok(5), || panic!("not yet")).unwrap(), 5);
+        assert!(tracker.decode(&policy, fail::<u16>, || panic!("not yet")).is_err());
+
+        assert!(!tracker.is_downgraded());
+    }
+}
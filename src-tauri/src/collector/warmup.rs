@@ -0,0 +1,48 @@
+/// Discards the first `discard_count` samples of a collection session.
+/// Many devices return stale or garbage values immediately after a
+/// connection is established; skipping the first few samples avoids
+/// polluting the recorded session with this cold-start noise.
+pub struct WarmupFilter {
+    remaining: usize,
+}
+
+impl WarmupFilter {
+    pub fn new(discard_count: usize) -> Self {
+        Self {
+            remaining: discard_count,
+        }
+    }
+
+    /// Call once per collected sample. Returns `false` while the sample
+    /// should be discarded, `true` once warmup has elapsed and the
+    /// sample should be kept.
+    pub fn should_keep(&mut self) -> bool {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            false
+        } else {
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discards_exactly_the_configured_number_of_samples() {
+        let mut warmup = WarmupFilter::new(2);
+
+        assert!(!warmup.should_keep());
+        assert!(!warmup.should_keep());
+        assert!(warmup.should_keep());
+        assert!(warmup.should_keep());
+    }
+
+    #[test]
+    fn zero_discard_count_keeps_every_sample() {
+        let mut warmup = WarmupFilter::new(0);
+        assert!(warmup.should_keep());
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::{HashMap, VecDeque};
+
+/// Summary statistics over the samples currently held in a point's
+/// sliding window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowStats {
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    pub count: usize,
+}
+
+/// Maintains a fixed-size sliding window of recent samples per point, so
+/// the UI can query "what's this point's mean/min/max over the last N
+/// samples" without re-reading the full session history.
+pub struct WindowStatsTracker {
+    capacity: usize,
+    windows: HashMap<String, VecDeque<f64>>,
+}
+
+impl WindowStatsTracker {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            windows: HashMap::new(),
+        }
+    }
+
+    pub fn record(&mut self, point_id: &str, value: f64) {
+        let window = self
+            .windows
+            .entry(point_id.to_string())
+            .or_default();
+        if window.len() == self.capacity {
+            window.pop_front();
+        }
+        window.push_back(value);
+    }
+
+    /// Current window statistics for `point_id`, or `None` if nothing
+    /// has been recorded for it yet.
+    pub fn query(&self, point_id: &str) -> Option<WindowStats> {
+        let window = self.windows.get(point_id)?;
+        if window.is_empty() {
+            return None;
+        }
+
+        let count = window.len();
+        let sum: f64 = window.iter().sum();
+        let min = window.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = window.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        Some(WindowStats {
+            mean: sum / count as f64,
+            min,
+            max,
+            count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_returns_none_for_unknown_point() {
+        let tracker = WindowStatsTracker::new(3);
+        assert_eq!(tracker.query("temp"), None);
+    }
+
+    #[test]
+    fn window_drops_oldest_sample_beyond_capacity() {
+        let mut tracker = WindowStatsTracker::new(2);
+        tracker.record("temp", 10.0);
+        tracker.record("temp", 20.0);
+        tracker.record("temp", 30.0);
+
+        let stats = tracker.query("temp").unwrap();
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.min, 20.0);
+        assert_eq!(stats.max, 30.0);
+        assert_eq!(stats.mean, 25.0);
+    }
+}
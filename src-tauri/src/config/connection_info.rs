@@ -0,0 +1,69 @@
+use serde::Serialize;
+
+use crate::modbus::ConnectionState;
+
+use super::ConnectionConfig;
+
+/// A serializable snapshot of one connection's status and config, for a
+/// frontend to render directly instead of parsing a formatted string.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConnectionInfo {
+    pub state: ConnectionState,
+    pub ip: String,
+    pub port: u16,
+    pub slave_id: Option<u8>,
+    pub timeout_ms: u64,
+}
+
+/// Build a [`ConnectionInfo`] from `config` plus the caller-supplied
+/// `state`/`port`/`slave_id`, which live outside [`ConnectionConfig`]
+/// ([`ConnectionState`] is tracked by a [`crate::modbus::ConnectionSequencer`],
+/// `port` and `slave_id` are per-read concerns set on
+/// [`crate::modbus::AddressRange`], not per-connection).
+pub fn connection_info(config: &ConnectionConfig, state: ConnectionState, port: u16, slave_id: Option<u8>) -> ConnectionInfo {
+    ConnectionInfo {
+        state,
+        ip: config.ip.clone(),
+        port,
+        slave_id,
+        timeout_ms: config.timeout_ms,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ConnectionConfig {
+        ConnectionConfig {
+            ip: "192.168.1.10".to_string(),
+            timeout_ms: 1000,
+            connect_timeout_ms: None,
+            read_timeout_ms: None,
+        }
+    }
+
+    #[test]
+    fn the_built_info_carries_the_config_and_caller_supplied_fields() {
+        let info = connection_info(&config(), ConnectionState::Connected, 502, Some(1));
+
+        assert_eq!(info.state, ConnectionState::Connected);
+        assert_eq!(info.ip, "192.168.1.10");
+        assert_eq!(info.port, 502);
+        assert_eq!(info.slave_id, Some(1));
+        assert_eq!(info.timeout_ms, 1000);
+    }
+
+    #[test]
+    fn it_serializes_to_a_json_object_with_the_expected_keys() {
+        let info = connection_info(&config(), ConnectionState::Disconnected, 502, None);
+
+        let value = serde_json::to_value(&info).unwrap();
+
+        assert_eq!(value["ip"], "192.168.1.10");
+        assert_eq!(value["port"], 502);
+        assert_eq!(value["slave_id"], serde_json::Value::Null);
+        assert_eq!(value["timeout_ms"], 1000);
+        assert_eq!(value["state"], "Disconnected");
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+
+use super::ConnectionConfig;
+
+/// A single field-level change recorded when a [`ConnectionConfig`] is
+/// saved, so the UI can answer "what changed and when".
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    pub at: DateTime<Utc>,
+    pub field: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+/// Append-only log of [`ConnectionConfig`] changes, diffed field by
+/// field on every save rather than storing whole-config snapshots.
+#[derive(Debug, Default)]
+pub struct ConfigHistory {
+    changes: Vec<ConfigChange>,
+}
+
+impl ConfigHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Diff `old` against `new` and append one [`ConfigChange`] per
+    /// field that actually changed. A no-op save (nothing changed)
+    /// appends nothing.
+    pub fn record(&mut self, old: &ConnectionConfig, new: &ConnectionConfig, at: DateTime<Utc>) {
+        if old.ip != new.ip {
+            self.changes.push(ConfigChange {
+                at,
+                field: "ip".to_string(),
+                old_value: old.ip.clone(),
+                new_value: new.ip.clone(),
+            });
+        }
+        if old.timeout_ms != new.timeout_ms {
+            self.changes.push(ConfigChange {
+                at,
+                field: "timeout_ms".to_string(),
+                old_value: old.timeout_ms.to_string(),
+                new_value: new.timeout_ms.to_string(),
+            });
+        }
+    }
+
+    /// The full change history, oldest first.
+    pub fn entries(&self) -> &[ConfigChange] {
+        &self.changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn records_one_entry_per_changed_field_across_saves() {
+        let mut history = ConfigHistory::new();
+        let v1 = ConnectionConfig {
+            ip: "192.168.1.10".to_string(),
+            timeout_ms: 1000,
+            connect_timeout_ms: None,
+            read_timeout_ms: None,
+        };
+        let v2 = ConnectionConfig {
+            ip: "192.168.1.20".to_string(),
+            timeout_ms: 1000,
+            connect_timeout_ms: None,
+            read_timeout_ms: None,
+        };
+        let v3 = ConnectionConfig {
+            ip: "192.168.1.20".to_string(),
+            timeout_ms: 5000,
+            connect_timeout_ms: None,
+            read_timeout_ms: None,
+        };
+
+        history.record(&v1, &v2, at(0));
+        history.record(&v2, &v3, at(1));
+
+        let entries = history.entries();
+        assert_eq!(entries.len(), 2);
+
+        assert_eq!(entries[0].field, "ip");
+        assert_eq!(entries[0].old_value, "192.168.1.10");
+        assert_eq!(entries[0].new_value, "192.168.1.20");
+
+        assert_eq!(entries[1].field, "timeout_ms");
+        assert_eq!(entries[1].old_value, "1000");
+        assert_eq!(entries[1].new_value, "5000");
+    }
+
+    #[test]
+    fn no_op_save_records_nothing() {
+        let mut history = ConfigHistory::new();
+        let config = ConnectionConfig {
+            ip: "192.168.1.10".to_string(),
+            timeout_ms: 1000,
+            connect_timeout_ms: None,
+            read_timeout_ms: None,
+        };
+
+        history.record(&config, &config.clone(), at(0));
+
+        assert!(history.entries().is_empty());
+    }
+}
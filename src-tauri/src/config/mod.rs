@@ -0,0 +1,98 @@
+//! Connection configuration and the change history kept alongside it, so
+//! "why can't I connect today when it worked yesterday" has an answer.
+
+mod connection_info;
+mod history;
+mod persistence;
+mod profile;
+mod registry;
+mod validate;
+
+pub use connection_info::{connection_info, ConnectionInfo};
+pub use history::{ConfigChange, ConfigHistory};
+pub use persistence::{load_config_from_file, save_config_to_file};
+pub use profile::{ConnectionProfile, ProfileStore};
+pub use registry::{ConnectionRegistry, DEFAULT_CONNECTION_ID};
+pub use validate::validate_config;
+
+use serde::{Deserialize, Serialize};
+
+/// The subset of connection settings a user can edit from the UI.
+/// Deliberately plain data so it can be diffed field-by-field and
+/// (de)serialized straight to disk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionConfig {
+    pub ip: String,
+    /// Legacy shared timeout, applied to both connect and read when the
+    /// more specific fields below are unset. Kept so configs saved
+    /// before the split still deserialize and behave the same.
+    pub timeout_ms: u64,
+    /// Timeout for establishing the connection. `None` (the default for
+    /// older configs) falls back to [`ConnectionConfig::timeout_ms`];
+    /// see [`ConnectionConfig::connect_timeout_ms_or_default`].
+    #[serde(default)]
+    pub connect_timeout_ms: Option<u64>,
+    /// Timeout for an individual read, kept shorter than the connect
+    /// timeout so a stuck read is noticed quickly. `None` falls back to
+    /// [`ConnectionConfig::timeout_ms`]; see
+    /// [`ConnectionConfig::read_timeout_ms_or_default`].
+    #[serde(default)]
+    pub read_timeout_ms: Option<u64>,
+}
+
+impl ConnectionConfig {
+    /// The timeout to actually use when connecting: the explicit
+    /// [`ConnectionConfig::connect_timeout_ms`] if set, otherwise
+    /// [`ConnectionConfig::timeout_ms`].
+    pub fn connect_timeout_ms_or_default(&self) -> u64 {
+        self.connect_timeout_ms.unwrap_or(self.timeout_ms)
+    }
+
+    /// The timeout to actually use for a read: the explicit
+    /// [`ConnectionConfig::read_timeout_ms`] if set, otherwise
+    /// [`ConnectionConfig::timeout_ms`].
+    pub fn read_timeout_ms_or_default(&self) -> u64 {
+        self.read_timeout_ms.unwrap_or(self.timeout_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ConnectionConfig {
+        ConnectionConfig {
+            ip: "192.168.1.10".to_string(),
+            timeout_ms: 1000,
+            connect_timeout_ms: None,
+            read_timeout_ms: None,
+        }
+    }
+
+    #[test]
+    fn unset_connect_and_read_timeouts_fall_back_to_the_legacy_shared_timeout() {
+        let config = config();
+
+        assert_eq!(config.connect_timeout_ms_or_default(), 1000);
+        assert_eq!(config.read_timeout_ms_or_default(), 1000);
+    }
+
+    #[test]
+    fn explicit_connect_and_read_timeouts_take_priority_over_the_legacy_timeout() {
+        let mut config = config();
+        config.connect_timeout_ms = Some(5000);
+        config.read_timeout_ms = Some(200);
+
+        assert_eq!(config.connect_timeout_ms_or_default(), 5000);
+        assert_eq!(config.read_timeout_ms_or_default(), 200);
+    }
+
+    #[test]
+    fn a_config_without_the_new_fields_deserializes_with_them_unset() {
+        let config: ConnectionConfig =
+            serde_json::from_str(r#"{"ip":"192.168.1.10","timeout_ms":1000}"#).unwrap();
+
+        assert_eq!(config.connect_timeout_ms, None);
+        assert_eq!(config.read_timeout_ms, None);
+    }
+}
@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use crate::error::AppError;
+
+use super::ConnectionConfig;
+
+/// Write `config` to `path` as JSON, creating or truncating the file.
+pub fn save_config_to_file(path: &Path, config: &ConnectionConfig) -> Result<(), AppError> {
+    let json = serde_json::to_string_pretty(config).map_err(|err| AppError::InvalidConfig(err.to_string()))?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Read a [`ConnectionConfig`] previously written by
+/// [`save_config_to_file`]. If `path` doesn't exist, is unreadable, or
+/// contains JSON that no longer matches [`ConnectionConfig`] (e.g. a
+/// leftover file from an older, incompatible version), returns `None`
+/// (logging a warning unless the file is simply missing) so the caller
+/// can fall back to its own defaults, rather than failing startup over
+/// a config file that can simply be regenerated.
+pub fn load_config_from_file(path: &Path) -> Option<ConnectionConfig> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(err) => {
+            eprintln!("warn: 无法读取配置文件 {}：{err}", path.display());
+            return None;
+        }
+    };
+
+    match serde_json::from_str(&contents) {
+        Ok(config) => Some(config),
+        Err(err) => {
+            eprintln!("warn: 配置文件 {} 格式不兼容，将使用默认配置：{err}", path.display());
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_temp_file(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("modbus_recoder_config_{name}.json"));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn config() -> ConnectionConfig {
+        ConnectionConfig {
+            ip: "192.168.1.10".to_string(),
+            timeout_ms: 1000,
+            connect_timeout_ms: None,
+            read_timeout_ms: None,
+        }
+    }
+
+    #[test]
+    fn a_saved_config_loads_back_identical() {
+        let path = unique_temp_file("roundtrip");
+
+        save_config_to_file(&path, &config()).unwrap();
+        let loaded = load_config_from_file(&path);
+
+        assert_eq!(loaded, Some(config()));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn a_missing_file_loads_as_none_without_erroring() {
+        let path = unique_temp_file("missing");
+
+        assert_eq!(load_config_from_file(&path), None);
+    }
+
+    #[test]
+    fn corrupted_json_loads_as_none_instead_of_panicking() {
+        let path = unique_temp_file("corrupted");
+        std::fs::write(&path, "{ not valid json").unwrap();
+
+        assert_eq!(load_config_from_file(&path), None);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn json_with_an_incompatible_shape_loads_as_none() {
+        let path = unique_temp_file("incompatible");
+        std::fs::write(&path, r#"{"unrelated_field": 42}"#).unwrap();
+
+        assert_eq!(load_config_from_file(&path), None);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::modbus::AddressRange;
+
+use super::ConnectionConfig;
+
+/// A named, saveable combination of connection settings and the address
+/// ranges to poll — e.g. one profile for the lab bench and another for
+/// the production line, swapped without re-entering every field by hand.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionProfile {
+    pub connection: ConnectionConfig,
+    pub ranges: Vec<AddressRange>,
+}
+
+/// Every saved [`ConnectionProfile`], keyed by name, persisted to a
+/// single JSON file at `path`. Each method reads the current file
+/// before modifying it, so changes made by another process (or another
+/// call to this store) aren't clobbered by a stale in-memory copy.
+#[derive(Debug, Clone)]
+pub struct ProfileStore {
+    path: PathBuf,
+}
+
+impl ProfileStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Names of every saved profile, in no particular order.
+    pub fn list_profiles(&self) -> Result<Vec<String>, AppError> {
+        Ok(self.load_all()?.into_keys().collect())
+    }
+
+    /// Save `profile` under `name`, replacing any existing profile with
+    /// that name.
+    pub fn save_profile(&self, name: &str, profile: ConnectionProfile) -> Result<(), AppError> {
+        let mut profiles = self.load_all()?;
+        profiles.insert(name.to_string(), profile);
+        self.write_all(&profiles)
+    }
+
+    /// Load the profile saved under `name`, or `None` if no such profile
+    /// exists.
+    pub fn load_profile(&self, name: &str) -> Result<Option<ConnectionProfile>, AppError> {
+        Ok(self.load_all()?.remove(name))
+    }
+
+    /// Remove the profile saved under `name`, returning it if it
+    /// existed. A no-op for an unknown name.
+    pub fn delete_profile(&self, name: &str) -> Result<Option<ConnectionProfile>, AppError> {
+        let mut profiles = self.load_all()?;
+        let removed = profiles.remove(name);
+        self.write_all(&profiles)?;
+        Ok(removed)
+    }
+
+    fn load_all(&self) -> Result<HashMap<String, ConnectionProfile>, AppError> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|err| AppError::InvalidConfig(err.to_string())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn write_all(&self, profiles: &HashMap<String, ConnectionProfile>) -> Result<(), AppError> {
+        let json = serde_json::to_string_pretty(profiles).map_err(|err| AppError::InvalidConfig(err.to_string()))?;
+        std::fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_temp_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("modbus_recoder_profiles_{name}.json"));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn profile(ip: &str) -> ConnectionProfile {
+        ConnectionProfile {
+            connection: ConnectionConfig {
+                ip: ip.to_string(),
+                timeout_ms: 1000,
+                connect_timeout_ms: None,
+                read_timeout_ms: None,
+            },
+            ranges: vec![AddressRange { start: 0, count: 10, slave_id: None }],
+        }
+    }
+
+    #[test]
+    fn a_saved_profile_loads_back_identical() {
+        let path = unique_temp_file("roundtrip");
+        let store = ProfileStore::new(path.clone());
+        store.save_profile("lab", profile("192.168.1.10")).unwrap();
+
+        let loaded = store.load_profile("lab").unwrap();
+
+        assert_eq!(loaded, Some(profile("192.168.1.10")));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn loading_an_unknown_profile_returns_none() {
+        let store = ProfileStore::new(unique_temp_file("unknown"));
+        assert_eq!(store.load_profile("missing").unwrap(), None);
+    }
+
+    #[test]
+    fn multiple_profiles_are_tracked_independently_and_listed_together() {
+        let path = unique_temp_file("multiple");
+        let store = ProfileStore::new(path.clone());
+        store.save_profile("lab", profile("192.168.1.10")).unwrap();
+        store.save_profile("line", profile("192.168.1.20")).unwrap();
+
+        let mut names = store.list_profiles().unwrap();
+        names.sort();
+
+        assert_eq!(names, vec!["lab".to_string(), "line".to_string()]);
+        assert_eq!(store.load_profile("lab").unwrap().unwrap().connection.ip, "192.168.1.10");
+        assert_eq!(store.load_profile("line").unwrap().unwrap().connection.ip, "192.168.1.20");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn deleting_a_profile_removes_it_but_leaves_the_others() {
+        let path = unique_temp_file("delete");
+        let store = ProfileStore::new(path.clone());
+        store.save_profile("lab", profile("192.168.1.10")).unwrap();
+        store.save_profile("line", profile("192.168.1.20")).unwrap();
+
+        let removed = store.delete_profile("lab").unwrap();
+
+        assert_eq!(removed, Some(profile("192.168.1.10")));
+        assert_eq!(store.load_profile("lab").unwrap(), None);
+        assert!(store.load_profile("line").unwrap().is_some());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn deleting_an_unknown_profile_is_a_no_op() {
+        let store = ProfileStore::new(unique_temp_file("delete-unknown"));
+        assert_eq!(store.delete_profile("missing").unwrap(), None);
+    }
+}
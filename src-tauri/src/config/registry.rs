@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use crate::error::AppError;
+
+use super::{validate_config, ConnectionConfig};
+
+/// The id used for commands that predate multi-connection support, so a
+/// single-connection caller does not need to know about connection ids.
+pub const DEFAULT_CONNECTION_ID: &str = "default";
+
+/// Keeps one [`ConnectionConfig`] per connection id, so a single process
+/// can talk to several devices (e.g. three TCP gateways) at once instead
+/// of being limited to one active connection.
+#[derive(Debug, Default)]
+pub struct ConnectionRegistry {
+    connections: HashMap<String, ConnectionConfig>,
+}
+
+impl ConnectionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate `config` and register it under `id`, replacing any
+    /// existing connection with that id. This is the only way to get a
+    /// config into the registry, so every stored config is guaranteed
+    /// to have passed [`validate_config`] — there is no separate path
+    /// (e.g. a connect call taking raw fields) that could store an
+    /// unvalidated or partial config under the same id.
+    pub fn create(&mut self, id: &str, config: ConnectionConfig) -> Result<(), AppError> {
+        validate_config(&config)?;
+        self.connections.insert(id.to_string(), config);
+        Ok(())
+    }
+
+    /// Drop the connection registered under `id`, returning it if it
+    /// existed. A no-op for an unknown id.
+    pub fn remove(&mut self, id: &str) -> Option<ConnectionConfig> {
+        self.connections.remove(id)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ConnectionConfig> {
+        self.connections.get(id)
+    }
+
+    pub fn ids(&self) -> Vec<&str> {
+        self.connections.keys().map(String::as_str).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(ip: &str) -> ConnectionConfig {
+        ConnectionConfig {
+            ip: ip.to_string(),
+            timeout_ms: 1000,
+            connect_timeout_ms: None,
+            read_timeout_ms: None,
+        }
+    }
+
+    #[test]
+    fn three_connections_are_tracked_independently_by_id() {
+        let mut registry = ConnectionRegistry::new();
+
+        registry.create("device-a", config("192.168.1.10")).unwrap();
+        registry.create("device-b", config("192.168.1.11")).unwrap();
+        registry.create("device-c", config("192.168.1.12")).unwrap();
+
+        assert_eq!(registry.get("device-a").unwrap().ip, "192.168.1.10");
+        assert_eq!(registry.get("device-b").unwrap().ip, "192.168.1.11");
+        assert_eq!(registry.get("device-c").unwrap().ip, "192.168.1.12");
+        assert_eq!(registry.ids().len(), 3);
+    }
+
+    #[test]
+    fn removing_a_connection_drops_it_but_leaves_the_others() {
+        let mut registry = ConnectionRegistry::new();
+        registry.create("device-a", config("192.168.1.10")).unwrap();
+        registry.create("device-b", config("192.168.1.11")).unwrap();
+
+        let removed = registry.remove("device-a");
+
+        assert_eq!(removed.unwrap().ip, "192.168.1.10");
+        assert!(registry.get("device-a").is_none());
+        assert!(registry.get("device-b").is_some());
+    }
+
+    #[test]
+    fn removing_an_unknown_id_is_a_no_op() {
+        let mut registry = ConnectionRegistry::new();
+        assert!(registry.remove("missing").is_none());
+    }
+
+    #[test]
+    fn a_single_connection_caller_can_use_the_default_id_for_backward_compatibility() {
+        let mut registry = ConnectionRegistry::new();
+        registry.create(DEFAULT_CONNECTION_ID, config("192.168.1.10")).unwrap();
+
+        assert_eq!(registry.get(DEFAULT_CONNECTION_ID).unwrap().ip, "192.168.1.10");
+    }
+
+    #[test]
+    fn an_invalid_config_is_rejected_and_never_stored() {
+        let mut registry = ConnectionRegistry::new();
+
+        let err = registry.create("device-a", config("not-an-ip")).unwrap_err();
+
+        assert!(matches!(err, AppError::InvalidConfig(_)));
+        assert!(registry.get("device-a").is_none());
+    }
+
+    #[test]
+    fn a_failed_create_does_not_disturb_an_existing_connection_with_the_same_id() {
+        let mut registry = ConnectionRegistry::new();
+        registry.create("device-a", config("192.168.1.10")).unwrap();
+
+        let err = registry.create("device-a", config("not-an-ip")).unwrap_err();
+
+        assert!(matches!(err, AppError::InvalidConfig(_)));
+        assert_eq!(registry.get("device-a").unwrap().ip, "192.168.1.10");
+    }
+}
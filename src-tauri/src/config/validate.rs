@@ -0,0 +1,114 @@
+use std::net::IpAddr;
+
+use crate::error::AppError;
+
+use super::ConnectionConfig;
+
+/// Validate a [`ConnectionConfig`] before it's applied, catching a
+/// malformed `ip` immediately instead of leaving it to surface as an
+/// opaque connect failure once [`std::net::SocketAddr`] parsing is
+/// attempted deep inside the connect path.
+pub fn validate_config(config: &ConnectionConfig) -> Result<(), AppError> {
+    if config.ip.trim().is_empty() {
+        return Err(AppError::InvalidConfig("ip 不能为空".to_string()));
+    }
+    if config.ip.parse::<IpAddr>().is_err() {
+        return Err(AppError::InvalidConfig(format!("ip 格式非法：{}", config.ip)));
+    }
+    if config.timeout_ms == 0 {
+        return Err(AppError::InvalidConfig("timeout_ms 不能为 0".to_string()));
+    }
+    if config.connect_timeout_ms == Some(0) {
+        return Err(AppError::InvalidConfig("connect_timeout_ms 不能为 0".to_string()));
+    }
+    if config.read_timeout_ms == Some(0) {
+        return Err(AppError::InvalidConfig("read_timeout_ms 不能为 0".to_string()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(ip: &str) -> ConnectionConfig {
+        ConnectionConfig {
+            ip: ip.to_string(),
+            timeout_ms: 1000,
+            connect_timeout_ms: None,
+            read_timeout_ms: None,
+        }
+    }
+
+    #[test]
+    fn a_valid_ipv4_address_passes() {
+        assert!(validate_config(&config("192.168.1.10")).is_ok());
+    }
+
+    #[test]
+    fn a_valid_ipv6_address_passes() {
+        assert!(validate_config(&config("::1")).is_ok());
+    }
+
+    #[test]
+    fn an_empty_ip_is_rejected() {
+        let err = validate_config(&config("")).unwrap_err();
+        assert!(matches!(err, AppError::InvalidConfig(_)));
+    }
+
+    #[test]
+    fn a_malformed_ip_string_is_rejected() {
+        let err = validate_config(&config("invalid_ip")).unwrap_err();
+        assert!(matches!(err, AppError::InvalidConfig(_)));
+    }
+
+    #[test]
+    fn a_hostname_is_rejected_since_this_config_field_holds_an_ip_not_a_host() {
+        let err = validate_config(&config("device.local")).unwrap_err();
+        assert!(matches!(err, AppError::InvalidConfig(_)));
+    }
+
+    #[test]
+    fn a_zero_timeout_is_rejected_with_a_message_distinct_from_an_ip_problem() {
+        let mut bad_timeout = config("192.168.1.10");
+        bad_timeout.timeout_ms = 0;
+
+        let err = validate_config(&bad_timeout).unwrap_err();
+
+        let AppError::InvalidConfig(message) = &err else {
+            panic!("expected InvalidConfig, got {err:?}");
+        };
+        assert!(message.contains("timeout_ms"));
+        assert!(!message.contains("ip"));
+    }
+
+    #[test]
+    fn a_zero_connect_timeout_is_rejected() {
+        let mut bad = config("192.168.1.10");
+        bad.connect_timeout_ms = Some(0);
+
+        let err = validate_config(&bad).unwrap_err();
+        let AppError::InvalidConfig(message) = &err else {
+            panic!("expected InvalidConfig, got {err:?}");
+        };
+        assert!(message.contains("connect_timeout_ms"));
+    }
+
+    #[test]
+    fn a_zero_read_timeout_is_rejected() {
+        let mut bad = config("192.168.1.10");
+        bad.read_timeout_ms = Some(0);
+
+        let err = validate_config(&bad).unwrap_err();
+        let AppError::InvalidConfig(message) = &err else {
+            panic!("expected InvalidConfig, got {err:?}");
+        };
+        assert!(message.contains("read_timeout_ms"));
+    }
+
+    #[test]
+    fn unset_connect_and_read_timeouts_pass_validation() {
+        assert!(validate_config(&config("192.168.1.10")).is_ok());
+    }
+}
@@ -0,0 +1,290 @@
+use thiserror::Error;
+
+use crate::modbus::AddressRange;
+
+/// Context attached to a read failure so the UI can explain exactly
+/// which request on the wire failed, instead of a bare error string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadErrorContext {
+    pub slave_id: u8,
+    pub range: AddressRange,
+    pub message: String,
+}
+
+/// Top-level error type returned by the modbus_recoder backend.
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("modbus protocol error: {0}")]
+    Modbus(String),
+
+    #[error("read failed for slave {} range {}..{} ({message})", .0.slave_id, .0.range.start, .0.range.start as u32 + .0.range.count as u32, message = .0.message)]
+    Read(ReadErrorContext),
+
+    #[error("invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A Modbus exception response from the device (function code with
+    /// the high bit set), as opposed to a transport-level failure. Build
+    /// with [`AppError::from_exception_code`] rather than constructing
+    /// directly, so every known code maps to a consistent name.
+    #[error("modbus exception 0x{code:02x}: {name}")]
+    ExceptionResponse { code: u8, name: String },
+
+    /// A read was aborted via [`crate::modbus::ReadCancellationToken`]
+    /// before it completed, as opposed to failing on its own.
+    #[error("read cancelled")]
+    Cancelled,
+
+    /// A read or write was attempted while
+    /// [`crate::modbus::ConnectionSequencer`] considers the device
+    /// disconnected. Distinct from [`AppError::InvalidConfig`] so a
+    /// caller (e.g. [`crate::collector::DataCollector`]) can recognize
+    /// and react to this specific condition — for example, pausing
+    /// itself instead of repeatedly failing the same way on every tick.
+    #[error("device not connected")]
+    NotConnected,
+
+    /// A write was attempted while [`crate::modbus::ReadOnlyGuard`] has
+    /// read-only mode enabled. Distinct from [`AppError::NotConnected`]:
+    /// this is an operator policy decision, not a transport condition,
+    /// so it is never retryable and should be surfaced as a deliberate
+    /// block rather than a transient failure.
+    #[error("write forbidden: read-only mode is enabled")]
+    WriteForbidden,
+
+    /// A coil/discrete-input read ([`crate::modbus::read_coils`],
+    /// [`crate::modbus::read_discrete_inputs`]) asked for more bits than
+    /// function codes 01/02 allow in one request. Distinct from
+    /// [`AddressRange::is_valid`]'s 65536-address-space overflow check,
+    /// which every register table is subject to regardless of kind —
+    /// this is the narrower, bit-read-specific limit.
+    #[error("bit read count {count} exceeds the function-code-01/02 limit of {max}")]
+    BitCountExceeded { count: u16, max: u16 },
+}
+
+/// Language for [`AppError::user_friendly_message`]. `Display`/`to_string`
+/// on [`AppError`] stays in English for logs; this is only for text shown
+/// directly to an end user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    Zh,
+    En,
+}
+
+impl Locale {
+    /// Best-effort detection from the `LANG` environment variable,
+    /// defaulting to English when it is unset or not recognized.
+    pub fn from_system() -> Self {
+        match std::env::var("LANG") {
+            Ok(lang) if lang.to_lowercase().starts_with("zh") => Locale::Zh,
+            _ => Locale::En,
+        }
+    }
+}
+
+impl AppError {
+    /// Build an [`AppError::ExceptionResponse`] from the Modbus exception
+    /// code returned in a device's response, mapping every code defined
+    /// by the protocol spec to its name; an unrecognized code still
+    /// produces a result, just with a generic name.
+    pub fn from_exception_code(code: u8) -> Self {
+        let name = match code {
+            0x01 => "非法功能码",
+            0x02 => "非法数据地址",
+            0x03 => "非法数据值",
+            0x04 => "从站设备故障",
+            0x05 => "确认",
+            0x06 => "从站设备忙",
+            0x08 => "存储奇偶校验错误",
+            0x0A => "网关路径不可用",
+            0x0B => "网关目标设备无响应",
+            _ => "未知异常",
+        }
+        .to_string();
+
+        AppError::ExceptionResponse { code, name }
+    }
+
+    /// Whether retrying the same request has a chance of succeeding:
+    /// true for transport-level hiccups (timeouts, dropped connections),
+    /// false for errors the device itself raised in response to the
+    /// request, which will fail identically on every retry.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, AppError::Io(_))
+    }
+
+    /// A message suitable for display to an end user, localized to
+    /// `locale`. Centralizes every variant's translated text so adding a
+    /// language only means adding arms here.
+    pub fn user_friendly_message(&self, locale: Locale) -> String {
+        match (self, locale) {
+            (AppError::Modbus(detail), Locale::Zh) => format!("Modbus 协议错误：{detail}"),
+            (AppError::Modbus(detail), Locale::En) => format!("Modbus protocol error: {detail}"),
+
+            (AppError::Read(ctx), Locale::Zh) => format!(
+                "从从站 {} 读取地址 {}..{} 失败：{}",
+                ctx.slave_id,
+                ctx.range.start,
+                ctx.range.start as u32 + ctx.range.count as u32,
+                ctx.message
+            ),
+            (AppError::Read(ctx), Locale::En) => format!(
+                "failed to read slave {} range {}..{}: {}",
+                ctx.slave_id,
+                ctx.range.start,
+                ctx.range.start as u32 + ctx.range.count as u32,
+                ctx.message
+            ),
+
+            (AppError::InvalidConfig(detail), Locale::Zh) => format!("配置无效：{detail}"),
+            (AppError::InvalidConfig(detail), Locale::En) => format!("invalid configuration: {detail}"),
+
+            (AppError::Io(err), Locale::Zh) => format!("IO 错误：{err}"),
+            (AppError::Io(err), Locale::En) => format!("io error: {err}"),
+
+            (AppError::ExceptionResponse { code, name }, Locale::Zh) => {
+                format!("设备返回 Modbus 异常 0x{code:02X}：{name}")
+            }
+            (AppError::ExceptionResponse { code, name }, Locale::En) => {
+                format!("device returned modbus exception 0x{code:02X}: {name}")
+            }
+
+            (AppError::Cancelled, Locale::Zh) => "读取已取消".to_string(),
+            (AppError::Cancelled, Locale::En) => "read was cancelled".to_string(),
+
+            (AppError::NotConnected, Locale::Zh) => "设备未连接".to_string(),
+            (AppError::NotConnected, Locale::En) => "device is not connected".to_string(),
+
+            (AppError::WriteForbidden, Locale::Zh) => "当前处于只读模式，写操作被拒绝".to_string(),
+            (AppError::WriteForbidden, Locale::En) => "write rejected: read-only mode is enabled".to_string(),
+
+            (AppError::BitCountExceeded { count, max }, Locale::Zh) => {
+                format!("读取数量 {count} 超过功能码 01/02 的上限 {max}")
+            }
+            (AppError::BitCountExceeded { count, max }, Locale::En) => {
+                format!("read count {count} exceeds the function-code-01/02 limit of {max}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_error_message_includes_slave_and_range() {
+        let err = AppError::Read(ReadErrorContext {
+            slave_id: 3,
+            range: AddressRange {
+                start: 100,
+                count: 10,
+                slave_id: None,
+            },
+            message: "timeout".to_string(),
+        });
+
+        let message = err.to_string();
+        assert!(message.contains("slave 3"));
+        assert!(message.contains("100..110"));
+        assert!(message.contains("timeout"));
+    }
+
+    #[test]
+    fn the_same_error_translates_to_a_different_non_empty_message_per_locale() {
+        let err = AppError::InvalidConfig("端口占用".to_string());
+
+        let zh = err.user_friendly_message(Locale::Zh);
+        let en = err.user_friendly_message(Locale::En);
+
+        assert!(!zh.is_empty());
+        assert!(!en.is_empty());
+        assert_ne!(zh, en);
+    }
+
+    #[test]
+    fn only_transport_level_errors_are_retryable() {
+        assert!(AppError::Io(std::io::Error::other("connection reset")).is_retryable());
+        assert!(!AppError::InvalidConfig("非法地址".to_string()).is_retryable());
+        assert!(!AppError::Modbus("illegal data address".to_string()).is_retryable());
+        assert!(!AppError::from_exception_code(0x02).is_retryable());
+    }
+
+    #[test]
+    fn known_exception_codes_map_to_their_named_meaning() {
+        let err = AppError::from_exception_code(0x02);
+
+        match err {
+            AppError::ExceptionResponse { code, name } => {
+                assert_eq!(code, 0x02);
+                assert_eq!(name, "非法数据地址");
+            }
+            other => panic!("expected ExceptionResponse, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn an_unrecognized_exception_code_still_produces_a_usable_error() {
+        let err = AppError::from_exception_code(0xFF);
+
+        match err {
+            AppError::ExceptionResponse { code, name } => {
+                assert_eq!(code, 0xFF);
+                assert_eq!(name, "未知异常");
+            }
+            other => panic!("expected ExceptionResponse, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn exception_response_user_friendly_message_includes_the_code_and_name() {
+        let err = AppError::from_exception_code(0x04);
+
+        let zh = err.user_friendly_message(Locale::Zh);
+        let en = err.user_friendly_message(Locale::En);
+
+        assert!(zh.contains("0x04"));
+        assert!(zh.contains("从站设备故障"));
+        assert!(en.contains("0x04"));
+        assert_ne!(zh, en);
+    }
+
+    #[test]
+    fn not_connected_translates_to_a_different_non_empty_message_per_locale() {
+        let zh = AppError::NotConnected.user_friendly_message(Locale::Zh);
+        let en = AppError::NotConnected.user_friendly_message(Locale::En);
+
+        assert!(!zh.is_empty());
+        assert!(!en.is_empty());
+        assert_ne!(zh, en);
+    }
+
+    #[test]
+    fn write_forbidden_is_not_retryable_and_translates_per_locale() {
+        assert!(!AppError::WriteForbidden.is_retryable());
+
+        let zh = AppError::WriteForbidden.user_friendly_message(Locale::Zh);
+        let en = AppError::WriteForbidden.user_friendly_message(Locale::En);
+
+        assert!(!zh.is_empty());
+        assert!(!en.is_empty());
+        assert_ne!(zh, en);
+    }
+
+    #[test]
+    fn bit_count_exceeded_is_not_retryable_and_mentions_both_numbers_per_locale() {
+        let err = AppError::BitCountExceeded { count: 2001, max: 2000 };
+
+        assert!(!err.is_retryable());
+
+        let zh = err.user_friendly_message(Locale::Zh);
+        let en = err.user_friendly_message(Locale::En);
+
+        assert!(zh.contains("2001") && zh.contains("2000"));
+        assert!(en.contains("2001") && en.contains("2000"));
+        assert_ne!(zh, en);
+    }
+}
@@ -0,0 +1,155 @@
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+use encoding_rs::Encoding;
+
+/// Which timezone a [`CsvOptions::format_timestamp`] call renders a
+/// timestamp in. Collection always records `at` as UTC internally
+/// ([`crate::collector::BatchReadResult::at`]); this only controls how
+/// it is displayed in exported CSV, so the conversion happens in one
+/// place instead of being redone ad hoc (and potentially inconsistently)
+/// by every consumer of the CSV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampZone {
+    Utc,
+    Local,
+}
+
+/// Delimiter, text encoding, byte-order-mark, and timestamp timezone
+/// choice for a CSV export. Lets locales that expect a semicolon
+/// separator, or a legacy encoding like GBK for older Excel builds, get
+/// a file their Excel opens correctly instead of mis-detecting commas or
+/// mojibake-ing Chinese text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub encoding: String,
+    pub with_bom: bool,
+    pub timestamp_zone: TimestampZone,
+}
+
+impl Default for CsvOptions {
+    /// Comma-separated, UTF-8, with a BOM, UTC timestamps — matches the
+    /// format the existing unconfigured exporters have always produced,
+    /// so opting in to [`CsvOptions`] is never required to keep the old
+    /// output.
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            encoding: "UTF-8".to_string(),
+            with_bom: true,
+            timestamp_zone: TimestampZone::Utc,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Re-encode already-rendered UTF-8 CSV `text` into the configured
+    /// encoding. Falls back to UTF-8 if `encoding` isn't a label
+    /// `encoding_rs` recognizes.
+    pub fn encode(&self, text: &str) -> Vec<u8> {
+        let (bytes, _, _) = self.resolved_encoding().encode(text);
+        bytes.into_owned()
+    }
+
+    /// The byte-order mark to prefix a fresh file with, or empty if
+    /// `with_bom` is unset or the encoding has no BOM convention (a BOM
+    /// only makes sense for UTF-8/UTF-16, not byte encodings like GBK).
+    pub fn bom(&self) -> &'static [u8] {
+        if self.with_bom && self.resolved_encoding() == encoding_rs::UTF_8 {
+            &[0xEF, 0xBB, 0xBF]
+        } else {
+            &[]
+        }
+    }
+
+    fn resolved_encoding(&self) -> &'static Encoding {
+        Encoding::for_label(self.encoding.as_bytes()).unwrap_or(encoding_rs::UTF_8)
+    }
+
+    /// Render `at` per `timestamp_zone`, always with millisecond
+    /// precision — so a whole-second timestamp doesn't render without
+    /// fractional digits while a sibling row with sub-second precision
+    /// does, and converting to local time never drops the milliseconds
+    /// the original UTC value carried.
+    pub fn format_timestamp(&self, at: DateTime<Utc>) -> String {
+        match self.timestamp_zone {
+            TimestampZone::Utc => at.to_rfc3339_opts(SecondsFormat::Millis, true),
+            TimestampZone::Local => at.with_timezone(&Local).to_rfc3339_opts(SecondsFormat::Millis, true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_default_is_comma_utf8_with_a_bom() {
+        let options = CsvOptions::default();
+
+        assert_eq!(options.delimiter, ',');
+        assert_eq!(options.bom(), &[0xEF, 0xBB, 0xBF]);
+        assert_eq!(options.encode("a,b"), b"a,b");
+    }
+
+    #[test]
+    fn gbk_encoding_has_no_bom_even_when_requested() {
+        let options = CsvOptions {
+            delimiter: ';',
+            encoding: "GBK".to_string(),
+            with_bom: true,
+            timestamp_zone: TimestampZone::Utc,
+        };
+
+        assert!(options.bom().is_empty());
+    }
+
+    #[test]
+    fn gbk_round_trips_chinese_text_through_decoding() {
+        let options = CsvOptions {
+            delimiter: ',',
+            encoding: "GBK".to_string(),
+            with_bom: false,
+            timestamp_zone: TimestampZone::Utc,
+        };
+
+        let encoded = options.encode("温度");
+        let (decoded, _, had_errors) = encoding_rs::GBK.decode(&encoded);
+
+        assert!(!had_errors);
+        assert_eq!(decoded, "温度");
+    }
+
+    #[test]
+    fn an_unrecognized_encoding_label_falls_back_to_utf8() {
+        let options = CsvOptions {
+            delimiter: ',',
+            encoding: "not-a-real-encoding".to_string(),
+            with_bom: false,
+            timestamp_zone: TimestampZone::Utc,
+        };
+
+        assert_eq!(options.encode("abc"), b"abc");
+    }
+
+    #[test]
+    fn utc_is_the_default_timestamp_zone_and_keeps_millisecond_precision() {
+        let options = CsvOptions::default();
+        let at = DateTime::from_timestamp(0, 1_000_000).unwrap();
+
+        assert_eq!(options.format_timestamp(at), "1970-01-01T00:00:00.001Z");
+    }
+
+    #[test]
+    fn local_renders_the_same_instant_with_a_non_utc_offset_when_one_applies() {
+        let options = CsvOptions {
+            timestamp_zone: TimestampZone::Local,
+            ..CsvOptions::default()
+        };
+        let at = DateTime::from_timestamp(0, 1_000_000).unwrap();
+
+        let rendered = options.format_timestamp(at);
+
+        assert!(rendered.starts_with("1970-01-01") || rendered.starts_with("1969-12-31"));
+        assert!(rendered.contains(".001"));
+    }
+}
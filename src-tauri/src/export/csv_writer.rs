@@ -0,0 +1,34 @@
+use csv::WriterBuilder;
+
+/// Render `rows` as CSV text prefixed with a `sep=<delimiter>` hint line,
+/// so Excel opens the file with the correct delimiter instead of
+/// guessing (and getting it wrong for anything but a comma on some
+/// locales).
+pub fn csv_with_sep_hint(rows: &[Vec<String>], delimiter: u8) -> Result<String, csv::Error> {
+    let mut output = format!("sep={}\n", delimiter as char);
+
+    let mut writer = WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(Vec::new());
+    for row in rows {
+        writer.write_record(row)?;
+    }
+    let body = writer.into_inner().expect("in-memory writer never fails to flush");
+    output.push_str(&String::from_utf8_lossy(&body));
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_starts_with_the_sep_hint_line() {
+        let rows = vec![vec!["a".to_string(), "b".to_string()]];
+        let csv = csv_with_sep_hint(&rows, b',').unwrap();
+
+        assert!(csv.starts_with("sep=,\n"));
+        assert!(csv.contains("a,b"));
+    }
+}
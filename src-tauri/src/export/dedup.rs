@@ -0,0 +1,193 @@
+use std::io::{self, Write};
+
+use chrono::{DateTime, Utc};
+
+use crate::collector::BatchReadResult;
+
+use super::CsvOptions;
+
+/// One or more consecutive [`BatchReadResult`]s with byte-identical
+/// `readings`, collapsed into a single row. `repeat_count` is how many
+/// batches collapsed into this row, and `duration` spans from the first
+/// batch's timestamp to the last.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DedupedBatch {
+    pub at: DateTime<Utc>,
+    pub readings: Vec<(crate::modbus::AddressRange, Vec<u16>)>,
+    pub repeat_count: usize,
+    pub duration: chrono::Duration,
+}
+
+/// Collapse runs of consecutive batches whose `readings` are identical
+/// (the device produced no change) into one [`DedupedBatch`] per run,
+/// so an export writes one row per run instead of one per batch.
+/// Non-consecutive duplicates are left as separate rows.
+pub fn dedup_consecutive(batches: &[BatchReadResult]) -> Vec<DedupedBatch> {
+    let mut result: Vec<DedupedBatch> = Vec::new();
+
+    for batch in batches {
+        match result.last_mut() {
+            Some(last) if last.readings == batch.readings => {
+                last.repeat_count += 1;
+                last.duration = batch.at - last.at;
+            }
+            _ => result.push(DedupedBatch {
+                at: batch.at,
+                readings: batch.readings.clone(),
+                repeat_count: 1,
+                duration: chrono::Duration::zero(),
+            }),
+        }
+    }
+
+    result
+}
+
+/// Write `batches` as CSV, one row per `(range, registers)` reading. If
+/// `dedup_consecutive` is set, consecutive batches with identical
+/// readings are collapsed via [`dedup_consecutive()`] first and two
+/// extra columns (`repeat_count`, `duration_secs`) are added. `options`
+/// controls the delimiter, text encoding, and BOM; `None` keeps the
+/// historical comma/UTF-8/BOM format.
+pub fn export_csv_with_dedup<W: Write>(
+    results: &[BatchReadResult],
+    dedup: bool,
+    writer: &mut W,
+    options: Option<&CsvOptions>,
+) -> io::Result<()> {
+    let default_options = CsvOptions::default();
+    let options = options.unwrap_or(&default_options);
+    let mut body = Vec::new();
+
+    if !dedup {
+        let mut csv = csv::WriterBuilder::new().delimiter(options.delimiter as u8).from_writer(&mut body);
+        csv.write_record(["at", "range_start", "range_count", "registers"])
+            .map_err(io::Error::other)?;
+        for batch in results {
+            write_rows(&mut csv, options, batch.at, &batch.readings, None)?;
+        }
+        csv.flush()?;
+    } else {
+        let mut csv = csv::WriterBuilder::new().delimiter(options.delimiter as u8).from_writer(&mut body);
+        csv.write_record(["at", "range_start", "range_count", "registers", "repeat_count", "duration_secs"])
+            .map_err(io::Error::other)?;
+        for deduped in dedup_consecutive(results) {
+            write_rows(
+                &mut csv,
+                options,
+                deduped.at,
+                &deduped.readings,
+                Some((deduped.repeat_count, deduped.duration.num_seconds())),
+            )?;
+        }
+        csv.flush()?;
+    }
+
+    writer.write_all(options.bom())?;
+    writer.write_all(&options.encode(&String::from_utf8_lossy(&body)))
+}
+
+fn write_rows<W: Write>(
+    csv: &mut csv::Writer<W>,
+    options: &CsvOptions,
+    at: DateTime<Utc>,
+    readings: &[(crate::modbus::AddressRange, Vec<u16>)],
+    repeat_and_duration: Option<(usize, i64)>,
+) -> io::Result<()> {
+    for (range, registers) in readings {
+        let values = registers.iter().map(u16::to_string).collect::<Vec<_>>().join(";");
+        let mut record = vec![options.format_timestamp(at), range.start.to_string(), range.count.to_string(), values];
+        if let Some((repeat_count, duration_secs)) = repeat_and_duration {
+            record.push(repeat_count.to_string());
+            record.push(duration_secs.to_string());
+        }
+        csv.write_record(&record).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::TimestampZone;
+    use crate::modbus::AddressRange;
+
+    fn batch(at_secs: i64, start: u16) -> BatchReadResult {
+        BatchReadResult {
+            at: DateTime::from_timestamp(at_secs, 0).unwrap(),
+            readings: vec![(
+                AddressRange {
+                    start,
+                    count: 1,
+                    slave_id: None,
+                },
+                vec![42],
+            )],
+            actual_interval_ms: None,
+        }
+    }
+
+    #[test]
+    fn five_identical_consecutive_batches_collapse_to_one_row_with_repeat_count_five() {
+        let batches: Vec<BatchReadResult> = (0..5).map(|i| batch(i, 0)).collect();
+
+        let deduped = dedup_consecutive(&batches);
+
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].repeat_count, 5);
+        assert_eq!(deduped[0].duration, chrono::Duration::seconds(4));
+    }
+
+    #[test]
+    fn a_changed_value_breaks_the_run_into_two_rows() {
+        let batches = vec![batch(0, 0), batch(1, 0), batch(2, 10)];
+
+        let deduped = dedup_consecutive(&batches);
+
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].repeat_count, 2);
+        assert_eq!(deduped[1].repeat_count, 1);
+    }
+
+    #[test]
+    fn export_csv_with_dedup_enabled_adds_repeat_count_and_duration_columns() {
+        let batches: Vec<BatchReadResult> = (0..5).map(|i| batch(i, 0)).collect();
+        let mut output = Vec::new();
+
+        export_csv_with_dedup(&batches, true, &mut output, None).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0].trim_start_matches('\u{FEFF}'), "at,range_start,range_count,registers,repeat_count,duration_secs");
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].ends_with("5,4"));
+    }
+
+    #[test]
+    fn export_csv_without_dedup_writes_one_row_per_batch() {
+        let batches: Vec<BatchReadResult> = (0..5).map(|i| batch(i, 0)).collect();
+        let mut output = Vec::new();
+
+        export_csv_with_dedup(&batches, false, &mut output, None).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text.lines().count(), 6);
+    }
+
+    #[test]
+    fn custom_options_use_the_requested_delimiter_and_omit_the_bom() {
+        let batches: Vec<BatchReadResult> = (0..2).map(|i| batch(i, 0)).collect();
+        let mut output = Vec::new();
+        let options = CsvOptions {
+            delimiter: ';',
+            encoding: "UTF-8".to_string(),
+            with_bom: false,
+            timestamp_zone: TimestampZone::Utc,
+        };
+
+        export_csv_with_dedup(&batches, false, &mut output, Some(&options)).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        assert!(text.starts_with("at;range_start;range_count;registers"));
+    }
+}
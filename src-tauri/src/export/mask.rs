@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+const MASK: &str = "***";
+
+/// Replace the value of every labeled export row whose label is in
+/// `sensitive_tags` with a fixed mask, so exported CSVs don't leak
+/// values the user flagged as sensitive (e.g. recipe parameters).
+pub fn mask_labeled_values(
+    rows: &[(String, String)],
+    sensitive_tags: &HashSet<String>,
+) -> Vec<(String, String)> {
+    rows.iter()
+        .map(|(label, value)| {
+            if sensitive_tags.contains(label) {
+                (label.clone(), MASK.to_string())
+            } else {
+                (label.clone(), value.clone())
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_only_sensitive_tags() {
+        let rows = vec![
+            ("TankLevel".to_string(), "512".to_string()),
+            ("RecipeKey".to_string(), "42".to_string()),
+        ];
+        let sensitive: HashSet<String> = ["RecipeKey".to_string()].into_iter().collect();
+
+        let masked = mask_labeled_values(&rows, &sensitive);
+
+        assert_eq!(masked[0], ("TankLevel".to_string(), "512".to_string()));
+        assert_eq!(masked[1], ("RecipeKey".to_string(), "***".to_string()));
+    }
+}
@@ -0,0 +1,26 @@
+//! Exporters that turn collected register data into formats suitable for
+//! sharing with third-party tooling (CSV, raw hex dumps, ...).
+
+mod csv_options;
+mod csv_writer;
+mod dedup;
+mod mask;
+mod ndjson;
+mod progress;
+mod raw;
+mod retention;
+mod streaming_writer;
+mod tag_map;
+mod xlsx;
+
+pub use csv_options::{CsvOptions, TimestampZone};
+pub use csv_writer::csv_with_sep_hint;
+pub use dedup::{dedup_consecutive, export_csv_with_dedup, DedupedBatch};
+pub use mask::mask_labeled_values;
+pub use ndjson::export_ndjson;
+pub use progress::{export_with_progress, ExportCancellationToken, ExportProgress};
+pub use raw::export_raw_hex;
+pub use retention::{apply_retention, RetentionPolicy};
+pub use streaming_writer::CsvWriter;
+pub use tag_map::TagMap;
+pub use xlsx::export_xlsx;
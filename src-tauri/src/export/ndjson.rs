@@ -0,0 +1,52 @@
+use std::io::{self, Write};
+
+use crate::collector::BatchReadResult;
+
+/// Write `results` as newline-delimited JSON (one [`BatchReadResult`]
+/// per line), streaming straight to `writer` instead of building the
+/// whole array in memory first. This keeps memory flat regardless of
+/// how many batches are exported.
+pub fn export_ndjson<W: Write>(results: &[BatchReadResult], writer: &mut W) -> io::Result<()> {
+    for result in results {
+        serde_json::to_writer(&mut *writer, result).map_err(io::Error::other)?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modbus::AddressRange;
+    use chrono::DateTime;
+
+    fn batch(start: u16) -> BatchReadResult {
+        BatchReadResult {
+            at: DateTime::from_timestamp(0, 0).unwrap(),
+            readings: vec![(AddressRange { start, count: 1, slave_id: None }, vec![42])],
+            actual_interval_ms: None,
+        }
+    }
+
+    #[test]
+    fn writes_one_valid_json_object_per_line() {
+        let results = vec![batch(0), batch(10)];
+        let mut output = Vec::new();
+
+        export_ndjson(&results, &mut output).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            assert!(serde_json::from_str::<serde_json::Value>(line).is_ok());
+        }
+    }
+
+    #[test]
+    fn empty_input_writes_nothing() {
+        let mut output = Vec::new();
+        export_ndjson(&[], &mut output).unwrap();
+        assert!(output.is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::collector::BatchReadResult;
+use crate::error::AppError;
+
+/// Cooperative cancellation flag for a long-running export, checked
+/// between batches so a user-initiated cancel takes effect within one
+/// batch instead of waiting for the whole export to finish.
+#[derive(Debug, Default, Clone)]
+pub struct ExportCancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl ExportCancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Progress snapshot emitted while exporting: how many batches have
+/// been written so far, out of the total that were queued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportProgress {
+    pub written: usize,
+    pub total: usize,
+}
+
+/// Write every batch in `batches` via `write_batch`, calling
+/// `on_progress` after each one so a UI can show live progress instead
+/// of only finding out when the export finishes. Stops early — without
+/// returning an error — as soon as `cancel` is signalled, returning how
+/// many batches were actually written.
+pub fn export_with_progress<W>(
+    batches: &[BatchReadResult],
+    cancel: &ExportCancellationToken,
+    mut write_batch: W,
+    mut on_progress: impl FnMut(ExportProgress),
+) -> Result<usize, AppError>
+where
+    W: FnMut(&BatchReadResult) -> Result<(), AppError>,
+{
+    let total = batches.len();
+    let mut written = 0;
+
+    for batch in batches {
+        if cancel.is_cancelled() {
+            break;
+        }
+
+        write_batch(batch)?;
+        written += 1;
+        on_progress(ExportProgress { written, total });
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    fn batch() -> BatchReadResult {
+        BatchReadResult {
+            at: DateTime::from_timestamp(0, 0).unwrap(),
+            readings: vec![],
+            actual_interval_ms: None,
+        }
+    }
+
+    #[test]
+    fn written_increases_monotonically_to_total_across_every_progress_event() {
+        let batches = vec![batch(), batch(), batch()];
+        let mut events = Vec::new();
+
+        let written = export_with_progress(&batches, &ExportCancellationToken::new(), |_| Ok(()), |progress| events.push(progress)).unwrap();
+
+        assert_eq!(written, 3);
+        assert_eq!(events.len(), 3);
+        assert_eq!(events.iter().map(|p| p.written).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert!(events.iter().all(|p| p.total == 3));
+    }
+
+    #[test]
+    fn cancelling_after_the_first_batch_stops_the_export_early() {
+        let batches = vec![batch(), batch(), batch()];
+        let cancel = ExportCancellationToken::new();
+        let mut processed = 0;
+
+        let written = export_with_progress(
+            &batches,
+            &cancel,
+            |_| {
+                processed += 1;
+                if processed == 1 {
+                    cancel.cancel();
+                }
+                Ok(())
+            },
+            |_| {},
+        )
+        .unwrap();
+
+        assert_eq!(written, 1);
+    }
+
+    #[test]
+    fn a_failed_write_stops_the_export_and_reports_the_error() {
+        let batches = vec![batch(), batch()];
+
+        let result = export_with_progress(
+            &batches,
+            &ExportCancellationToken::new(),
+            |_| Err(AppError::Io(std::io::Error::other("disk full"))),
+            |_| {},
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,50 @@
+use crate::modbus::ByteOrder;
+
+/// Export a sequence of raw `u16` registers as per-register hex byte
+/// strings (e.g. `"1234"`), using `order` to decide which byte comes
+/// first. This is intended for binary comparison against a third-party
+/// Modbus tool that expects a specific byte order, independent of the
+/// higher-level decoded value types.
+pub fn export_raw_hex(registers: &[u16], order: ByteOrder) -> Vec<String> {
+    registers
+        .iter()
+        .map(|register| {
+            let [high, low] = register.to_be_bytes();
+            match order {
+                ByteOrder::BigEndian => format!("{:02X}{:02X}", high, low),
+                ByteOrder::LittleEndian => format!("{:02X}{:02X}", low, high),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_register_differs_by_byte_order_and_is_correct() {
+        let registers = [0x1234u16];
+
+        let big = export_raw_hex(&registers, ByteOrder::BigEndian);
+        let little = export_raw_hex(&registers, ByteOrder::LittleEndian);
+
+        assert_eq!(big, vec!["1234".to_string()]);
+        assert_eq!(little, vec!["3412".to_string()]);
+        assert_ne!(big, little);
+    }
+
+    #[test]
+    fn a_32_bit_value_spanning_two_registers_is_hex_formatted_in_full_not_just_its_first_u16() {
+        // 0x01020304 split big-endian across two registers: the hex
+        // formatting isn't limited to the first (or any single) u16 —
+        // every register in the value gets its own correctly-ordered
+        // hex string.
+        let registers = [0x0102u16, 0x0304u16];
+
+        let hex = export_raw_hex(&registers, ByteOrder::BigEndian);
+
+        assert_eq!(hex, vec!["0102".to_string(), "0304".to_string()]);
+        assert_eq!(hex.concat(), "01020304");
+    }
+}
@@ -0,0 +1,113 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Retention policy applied to the directory of exported/recorded CSV
+/// files so a long-running lab session doesn't fill up the disk.
+#[derive(Debug, Clone, Copy)]
+pub struct RetentionPolicy {
+    /// Files whose modification time is older than `now - max_age` are removed.
+    pub max_age: Duration,
+    /// If set, keep only the `max_files` most recently modified files
+    /// even if they are within `max_age`.
+    pub max_files: Option<usize>,
+}
+
+/// Apply `policy` to every regular file directly inside `dir`, deleting
+/// the ones that fall outside the retention window. Returns the paths
+/// that were removed.
+pub fn apply_retention(dir: &Path, policy: &RetentionPolicy) -> io::Result<Vec<PathBuf>> {
+    let now = SystemTime::now();
+
+    let mut files: Vec<(PathBuf, SystemTime)> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((entry.path(), modified))
+        })
+        .collect();
+
+    // Newest first, so `max_files` keeps the most recent ones.
+    files.sort_by_key(|(_, modified)| std::cmp::Reverse(*modified));
+
+    let mut removed = Vec::new();
+    for (index, (path, modified)) in files.into_iter().enumerate() {
+        let too_old = now
+            .duration_since(modified)
+            .map(|age| age > policy.max_age)
+            .unwrap_or(false);
+        let beyond_limit = policy.max_files.is_some_and(|limit| index >= limit);
+
+        if too_old || beyond_limit {
+            fs::remove_file(&path)?;
+            removed.push(path);
+        }
+    }
+
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    fn unique_temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("modbus_recoder_retention_{name}"));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn keeps_only_max_files_most_recent() {
+        let dir = unique_temp_dir("max_files");
+        for (name, age_secs) in [("a.csv", 30), ("b.csv", 20), ("c.csv", 10)] {
+            let path = dir.join(name);
+            File::create(&path).unwrap();
+            let modified = SystemTime::now() - Duration::from_secs(age_secs);
+            File::open(&path).unwrap().set_modified(modified).unwrap();
+        }
+
+        let removed = apply_retention(
+            &dir,
+            &RetentionPolicy {
+                max_age: Duration::from_secs(3600),
+                max_files: Some(2),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(removed, vec![dir.join("a.csv")]);
+        assert!(!dir.join("a.csv").exists());
+        assert!(dir.join("b.csv").exists());
+        assert!(dir.join("c.csv").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn removes_files_older_than_max_age() {
+        let dir = unique_temp_dir("max_age");
+        let old = dir.join("old.csv");
+        File::create(&old).unwrap();
+        let modified = SystemTime::now() - Duration::from_secs(3600);
+        File::open(&old).unwrap().set_modified(modified).unwrap();
+
+        let removed = apply_retention(
+            &dir,
+            &RetentionPolicy {
+                max_age: Duration::from_secs(60),
+                max_files: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(removed, vec![old.clone()]);
+        assert!(!old.exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
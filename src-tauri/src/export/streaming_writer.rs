@@ -0,0 +1,145 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use crate::collector::BatchReadResult;
+use crate::error::AppError;
+
+/// An open CSV export file that batches are appended to as they arrive,
+/// rather than accumulated in a `Vec` and written all at once — a long
+/// collection session would otherwise hold every batch in memory until
+/// the export finally happens, which doesn't bound for an
+/// hours-long run. Each [`Self::write_batch`] call flushes immediately,
+/// so a crash loses at most the in-flight batch.
+pub struct CsvWriter {
+    writer: Option<csv::Writer<BufWriter<File>>>,
+    rows_written: usize,
+}
+
+impl CsvWriter {
+    /// Create `path`, truncating it if it already exists, and write the
+    /// header row.
+    pub fn create(path: &Path) -> Result<Self, AppError> {
+        let file = File::create(path)?;
+        let mut writer = csv::WriterBuilder::new().from_writer(BufWriter::new(file));
+        writer
+            .write_record(["at", "range_start", "range_count", "registers"])
+            .map_err(|err| AppError::InvalidConfig(err.to_string()))?;
+        writer.flush()?;
+
+        Ok(Self { writer: Some(writer), rows_written: 0 })
+    }
+
+    /// Append one batch, one row per `(range, registers)` reading.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after [`Self::close`].
+    pub fn write_batch(&mut self, batch: &BatchReadResult) -> Result<(), AppError> {
+        let writer = self.writer.as_mut().expect("write_batch called on a closed CsvWriter");
+        for (range, registers) in &batch.readings {
+            let values = registers.iter().map(u16::to_string).collect::<Vec<_>>().join(";");
+            writer
+                .write_record([batch.at.to_rfc3339(), range.start.to_string(), range.count.to_string(), values])
+                .map_err(|err| AppError::InvalidConfig(err.to_string()))?;
+            self.rows_written += 1;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Flush and close the file, returning the total number of data rows
+    /// written so far (not counting the header).
+    pub fn close(mut self) -> Result<usize, AppError> {
+        if let Some(mut writer) = self.writer.take() {
+            writer.flush()?;
+        }
+        Ok(self.rows_written)
+    }
+}
+
+impl Drop for CsvWriter {
+    /// Best-effort flush for a `CsvWriter` that is dropped without an
+    /// explicit [`Self::close`], so the last written batch isn't lost.
+    fn drop(&mut self) {
+        if let Some(writer) = self.writer.as_mut() {
+            let _ = writer.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modbus::AddressRange;
+    use chrono::DateTime;
+
+    fn batch(start: u16, registers: Vec<u16>) -> BatchReadResult {
+        BatchReadResult {
+            at: DateTime::from_timestamp(0, 0).unwrap(),
+            readings: vec![(AddressRange { start, count: registers.len() as u16, slave_id: None }, registers)],
+            actual_interval_ms: None,
+        }
+    }
+
+    fn unique_temp_file(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("modbus_recoder_streaming_{name}.csv"));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn each_write_batch_call_is_flushed_to_disk_immediately() {
+        let path = unique_temp_file("incremental");
+        let mut writer = CsvWriter::create(&path).unwrap();
+
+        writer.write_batch(&batch(0, vec![1, 2])).unwrap();
+        let after_first = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(after_first.lines().count(), 2);
+
+        writer.write_batch(&batch(10, vec![7])).unwrap();
+        let after_second = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(after_second.lines().count(), 3);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn close_returns_the_total_row_count_excluding_the_header() {
+        let path = unique_temp_file("close");
+        let mut writer = CsvWriter::create(&path).unwrap();
+
+        writer.write_batch(&batch(0, vec![1])).unwrap();
+        writer.write_batch(&batch(1, vec![2])).unwrap();
+
+        let rows = writer.close().unwrap();
+        assert_eq!(rows, 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn dropping_without_an_explicit_close_still_flushes_what_was_written() {
+        let path = unique_temp_file("drop");
+        {
+            let mut writer = CsvWriter::create(&path).unwrap();
+            writer.write_batch(&batch(0, vec![42])).unwrap();
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "write_batch called on a closed CsvWriter")]
+    fn writing_after_close_panics() {
+        // `close` consumes `self`, so a closed writer is constructed
+        // directly in its post-close state (`writer: None`) rather than
+        // through the public API, which has no way to hand back an
+        // already-closed value to call `write_batch` on.
+        let mut closed = CsvWriter { writer: None, rows_written: 0 };
+        let _ = closed.write_batch(&batch(0, vec![1]));
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+/// Maps a register address to the physical tag name a technician
+/// actually recognizes (e.g. `40001` -> `"TankLevel"`), so exported data
+/// is self-describing instead of a bare list of addresses.
+#[derive(Debug, Default)]
+pub struct TagMap {
+    labels: HashMap<u16, String>,
+}
+
+impl TagMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, address: u16, label: impl Into<String>) {
+        self.labels.insert(address, label.into());
+    }
+
+    /// The tag name for `address`, falling back to the address itself
+    /// (as a decimal string) when no mapping has been configured.
+    pub fn label_for(&self, address: u16) -> String {
+        self.labels
+            .get(&address)
+            .cloned()
+            .unwrap_or_else(|| address.to_string())
+    }
+
+    /// Relabel a batch of `(address, value)` readings as `(label, value)`
+    /// for export.
+    pub fn export_labeled(&self, readings: &[(u16, u16)]) -> Vec<(String, u16)> {
+        readings
+            .iter()
+            .map(|(address, value)| (self.label_for(*address), *value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapped_address_exports_its_tag_name() {
+        let mut tags = TagMap::new();
+        tags.set(40001, "TankLevel");
+
+        let labeled = tags.export_labeled(&[(40001, 512)]);
+
+        assert_eq!(labeled, vec![("TankLevel".to_string(), 512)]);
+    }
+
+    #[test]
+    fn unmapped_address_falls_back_to_its_numeric_form() {
+        let tags = TagMap::new();
+
+        let labeled = tags.export_labeled(&[(40002, 7)]);
+
+        assert_eq!(labeled, vec![("40002".to_string(), 7)]);
+    }
+}
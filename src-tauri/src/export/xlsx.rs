@@ -0,0 +1,110 @@
+use std::path::Path;
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+use crate::collector::BatchReadResult;
+use crate::error::AppError;
+
+const HEADER: [&str; 4] = ["at", "range_start", "range_count", "registers"];
+
+/// Write `batches` to an `.xlsx` workbook at `path`, one row per
+/// `(range, registers)` reading, with the same columns
+/// [`super::CsvWriter`] uses for CSV. Writes the worksheet in
+/// "constant memory" mode so a multi-hour collection session (tens of
+/// thousands of rows) streams straight to a temp file instead of being
+/// held as a grid in memory before saving — rows must therefore be
+/// written in increasing order, which the batch/reading iteration order
+/// already gives us.
+///
+/// [`BatchReadResult`] only ever records a batch that already read
+/// successfully, so there is no per-cell failure state to highlight
+/// here; that would need the per-range [`Result`] that
+/// [`crate::modbus::read_ranges_detailed`] produces before it collapses
+/// into a batch.
+pub fn export_xlsx(batches: &[BatchReadResult], path: &Path) -> Result<(), AppError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet_with_constant_memory();
+    let timestamp_format = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+
+    for (col, title) in HEADER.iter().enumerate() {
+        worksheet.write_string(0, col as u16, *title).map_err(xlsx_error)?;
+    }
+
+    let mut row = 1;
+    for batch in batches {
+        for (range, registers) in &batch.readings {
+            worksheet
+                .write_datetime_with_format(row, 0, batch.at.naive_utc(), &timestamp_format)
+                .map_err(xlsx_error)?;
+            worksheet.write_number(row, 1, range.start as f64).map_err(xlsx_error)?;
+            worksheet.write_number(row, 2, range.count as f64).map_err(xlsx_error)?;
+            let values = registers.iter().map(u16::to_string).collect::<Vec<_>>().join(";");
+            worksheet.write_string(row, 3, values).map_err(xlsx_error)?;
+            row += 1;
+        }
+    }
+
+    workbook.save(path).map_err(xlsx_error)
+}
+
+fn xlsx_error(err: XlsxError) -> AppError {
+    AppError::InvalidConfig(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modbus::AddressRange;
+    use chrono::DateTime;
+
+    fn unique_temp_file(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("modbus_recoder_xlsx_{name}.xlsx"));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn batch(start: u16, registers: Vec<u16>) -> BatchReadResult {
+        BatchReadResult {
+            at: DateTime::from_timestamp(0, 0).unwrap(),
+            readings: vec![(AddressRange { start, count: registers.len() as u16, slave_id: None }, registers)],
+            actual_interval_ms: None,
+        }
+    }
+
+    #[test]
+    fn writes_a_valid_workbook_with_a_header_and_one_row_per_reading() {
+        let path = unique_temp_file("basic");
+        let batches = vec![batch(0, vec![1, 2]), batch(10, vec![7])];
+
+        export_xlsx(&batches, &path).unwrap();
+
+        // rust_xlsxwriter has no public reader, so round-trip through
+        // `Workbook::new` is not possible; assert the file is at least a
+        // well-formed, non-empty xlsx (zip) container.
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(!bytes.is_empty());
+        assert_eq!(&bytes[0..2], b"PK");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn empty_input_still_produces_a_workbook_with_just_the_header() {
+        let path = unique_temp_file("empty");
+
+        export_xlsx(&[], &path).unwrap();
+
+        assert!(std::fs::metadata(&path).unwrap().len() > 0);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn an_unwritable_path_reports_an_error_instead_of_panicking() {
+        let path = Path::new("/nonexistent-directory/out.xlsx");
+
+        let result = export_xlsx(&[batch(0, vec![1])], path);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+
+/// Event emitted by a [`HealthMonitor`] when the connection's health
+/// crosses the configured threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthEvent {
+    /// The success rate dropped at or below the threshold.
+    Degraded,
+    /// The success rate recovered above the threshold after a degradation.
+    Recovered,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HealthState {
+    Healthy,
+    Degraded,
+}
+
+/// Passively tracks the success/failure of regular reads or writes to
+/// infer connection health, without issuing any extra probe requests.
+///
+/// Every observed outcome is folded into a sliding window; once the
+/// success rate within that window drops to or below `threshold`, a
+/// [`HealthEvent::Degraded`] is queued, and once it recovers above the
+/// threshold a [`HealthEvent::Recovered`] is queued.
+pub struct HealthMonitor {
+    window: VecDeque<bool>,
+    window_size: usize,
+    threshold: f64,
+    state: HealthState,
+    events: Vec<HealthEvent>,
+}
+
+impl HealthMonitor {
+    pub fn new(window_size: usize, threshold: f64) -> Self {
+        Self {
+            window: VecDeque::with_capacity(window_size),
+            window_size: window_size.max(1),
+            threshold,
+            state: HealthState::Healthy,
+            events: Vec::new(),
+        }
+    }
+
+    /// Record the outcome of a single read/write operation.
+    pub fn record(&mut self, success: bool) {
+        if self.window.len() == self.window_size {
+            self.window.pop_front();
+        }
+        self.window.push_back(success);
+
+        let rate = self.success_rate();
+        match self.state {
+            HealthState::Healthy if rate <= self.threshold => {
+                self.state = HealthState::Degraded;
+                self.events.push(HealthEvent::Degraded);
+            }
+            HealthState::Degraded if rate > self.threshold => {
+                self.state = HealthState::Healthy;
+                self.events.push(HealthEvent::Recovered);
+            }
+            _ => {}
+        }
+    }
+
+    /// Success rate within the current sliding window, in `[0.0, 1.0]`.
+    /// Returns `1.0` when no samples have been recorded yet.
+    pub fn success_rate(&self) -> f64 {
+        if self.window.is_empty() {
+            return 1.0;
+        }
+        let successes = self.window.iter().filter(|ok| **ok).count();
+        successes as f64 / self.window.len() as f64
+    }
+
+    /// Drain and return any health events queued since the last call.
+    pub fn take_events(&mut self) -> Vec<HealthEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+/// Tracks read and write health independently so a device that only
+/// refuses writes (common on read-mostly industrial gear) doesn't get
+/// flagged as fully unhealthy. When the write side degrades, the
+/// connection gracefully falls back to "read priority": the write queue
+/// is paused while reads keep flowing.
+pub struct ConnectionHealth {
+    read: HealthMonitor,
+    write: HealthMonitor,
+}
+
+impl ConnectionHealth {
+    pub fn new(window_size: usize, threshold: f64) -> Self {
+        Self {
+            read: HealthMonitor::new(window_size, threshold),
+            write: HealthMonitor::new(window_size, threshold),
+        }
+    }
+
+    pub fn record_read(&mut self, success: bool) {
+        self.read.record(success);
+    }
+
+    pub fn record_write(&mut self, success: bool) {
+        self.write.record(success);
+    }
+
+    pub fn read_healthy(&self) -> bool {
+        matches!(self.read.state, HealthState::Healthy)
+    }
+
+    /// Whether the write queue should be paused: writes are degraded.
+    /// Read collection is unaffected by this.
+    pub fn write_paused(&self) -> bool {
+        matches!(self.write.state, HealthState::Degraded)
+    }
+
+    pub fn take_read_events(&mut self) -> Vec<HealthEvent> {
+        self.read.take_events()
+    }
+
+    pub fn take_write_events(&mut self) -> Vec<HealthEvent> {
+        self.write.take_events()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_failures_degrade_then_recovery_restores_health() {
+        let mut monitor = HealthMonitor::new(4, 0.5);
+
+        monitor.record(false);
+        monitor.record(false);
+        monitor.record(false);
+        assert!(monitor.success_rate() < 0.5);
+        assert_eq!(monitor.take_events(), vec![HealthEvent::Degraded]);
+
+        monitor.record(true);
+        monitor.record(true);
+        monitor.record(true);
+        assert!(monitor.success_rate() > 0.5);
+        assert_eq!(monitor.take_events(), vec![HealthEvent::Recovered]);
+    }
+
+    #[test]
+    fn write_degradation_pauses_writes_without_affecting_reads() {
+        let mut health = ConnectionHealth::new(4, 0.5);
+
+        health.record_write(false);
+        health.record_write(false);
+        health.record_write(false);
+        health.record_read(true);
+        health.record_read(true);
+
+        assert!(health.write_paused());
+        assert!(health.read_healthy());
+        assert_eq!(health.take_write_events(), vec![HealthEvent::Degraded]);
+        assert!(health.take_read_events().is_empty());
+    }
+}
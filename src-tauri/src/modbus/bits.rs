@@ -0,0 +1,124 @@
+use std::future::Future;
+
+use crate::error::AppError;
+
+use super::address_range::validate_bit_read_count;
+use super::{AddressRange, RegisterKind};
+
+/// Unpack the bit-packed bytes a coil/discrete-input read response
+/// carries (function codes 01/02) into one `bool` per requested bit,
+/// LSB-first within each byte as specified by the Modbus protocol.
+pub fn unpack_bits(bytes: &[u8], count: usize) -> Vec<bool> {
+    (0..count)
+        .map(|index| {
+            let byte = bytes[index / 8];
+            (byte >> (index % 8)) & 1 == 1
+        })
+        .collect()
+}
+
+/// Read `range.count` bits of `register_kind` (coils or discrete
+/// inputs), validating the function-code-01/02 count cap before issuing
+/// the request and unpacking the response via [`unpack_bits`].
+/// `read_raw_bits` performs the actual wire request and returns the
+/// response's raw packed bytes; shared by [`read_coils`] and the
+/// discrete-input read that reuses it, so both function codes go through
+/// the same validation and unpacking logic.
+async fn read_bits<F, Fut>(range: AddressRange, register_kind: RegisterKind, read_raw_bits: F) -> Result<Vec<bool>, AppError>
+where
+    F: FnOnce(AddressRange) -> Fut,
+    Fut: Future<Output = Result<Vec<u8>, AppError>>,
+{
+    validate_bit_read_count(&range, register_kind)?;
+    let bytes = read_raw_bits(range).await?;
+    Ok(unpack_bits(&bytes, range.count as usize))
+}
+
+/// Read `range.count` coils (function code 01). This crate has no
+/// stateful connection type to hang a `&mut self` method off — every
+/// read in this crate (see [`super::ModbusReader`],
+/// [`super::read_ranges_detailed_by_kind`]) is modeled as a function
+/// injected with the actual wire call, not a method on a connection
+/// object — so `read_coils` follows that same shape: `read_raw_bits`
+/// performs the function-code-01 request and returns the raw packed
+/// response bytes, which this function validates the count of and
+/// unpacks.
+pub async fn read_coils<F, Fut>(range: AddressRange, read_raw_bits: F) -> Result<Vec<bool>, AppError>
+where
+    F: FnOnce(AddressRange) -> Fut,
+    Fut: Future<Output = Result<Vec<u8>, AppError>>,
+{
+    read_bits(range, RegisterKind::Coil, read_raw_bits).await
+}
+
+/// Read `range.count` discrete inputs (function code 02). Same shape as
+/// [`read_coils`] and for the same reason — no `&mut self` connection
+/// type exists in this crate for either to hang off — and shares its
+/// count validation and bit-unpacking via [`read_bits`].
+pub async fn read_discrete_inputs<F, Fut>(range: AddressRange, read_raw_bits: F) -> Result<Vec<bool>, AppError>
+where
+    F: FnOnce(AddressRange) -> Fut,
+    Fut: Future<Output = Result<Vec<u8>, AppError>>,
+{
+    read_bits(range, RegisterKind::DiscreteInput, read_raw_bits).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpacks_bits_lsb_first() {
+        // 0b0000_0101 -> bit0=1, bit1=0, bit2=1
+        let bytes = [0b0000_0101u8];
+        assert_eq!(unpack_bits(&bytes, 3), vec![true, false, true]);
+    }
+
+    #[test]
+    fn unpacks_across_multiple_bytes() {
+        let bytes = [0xFFu8, 0b0000_0001];
+        let bits = unpack_bits(&bytes, 9);
+        assert_eq!(bits.len(), 9);
+        assert!(bits[8]);
+    }
+
+    fn range(count: u16) -> AddressRange {
+        AddressRange { start: 0, count, slave_id: None }
+    }
+
+    #[tokio::test]
+    async fn read_coils_unpacks_the_raw_response_bytes() {
+        let bits = read_coils(range(3), |_range| async move { Ok(vec![0b0000_0101u8]) }).await.unwrap();
+        assert_eq!(bits, vec![true, false, true]);
+    }
+
+    #[tokio::test]
+    async fn read_coils_rejects_a_count_above_the_function_code_01_limit() {
+        let err = read_coils(range(2001), |_range| async move { panic!("should not reach the wire") })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AppError::BitCountExceeded { count: 2001, max: 2000 }));
+    }
+
+    #[tokio::test]
+    async fn read_coils_propagates_a_wire_error() {
+        let err = read_coils(range(1), |_range| async move { Err(AppError::NotConnected) }).await.unwrap_err();
+        assert!(matches!(err, AppError::NotConnected));
+    }
+
+    #[tokio::test]
+    async fn read_discrete_inputs_unpacks_the_raw_response_bytes() {
+        let bits = read_discrete_inputs(range(3), |_range| async move { Ok(vec![0b0000_0101u8]) }).await.unwrap();
+        assert_eq!(bits, vec![true, false, true]);
+    }
+
+    #[tokio::test]
+    async fn read_discrete_inputs_rejects_a_count_above_the_function_code_02_limit() {
+        let err = read_discrete_inputs(range(2001), |_range| async move { panic!("should not reach the wire") })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AppError::BitCountExceeded { count: 2001, max: 2000 }));
+    }
+}
@@ -0,0 +1,169 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+
+use serde::Serialize;
+
+use crate::error::AppError;
+
+/// Lifecycle state of a connection as seen by [`ConnectionSequencer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum ConnectionState {
+    Connected,
+    #[default]
+    Disconnected,
+}
+
+/// Serializes connect/disconnect against each other when both are
+/// asynchronous and may be requested in quick succession (e.g. a user
+/// double-clicking connect then disconnect). Each call is tagged with a
+/// generation number when it starts; if a later call starts before an
+/// earlier one's async work finishes, the earlier call's result is
+/// discarded instead of clobbering state the newer call already set.
+#[derive(Debug, Default, Clone)]
+pub struct ConnectionSequencer {
+    generation: Arc<AtomicU64>,
+    state: Arc<StdMutex<ConnectionState>>,
+}
+
+impl ConnectionSequencer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        *self.state.lock().unwrap()
+    }
+
+    /// Run `connect`, and on success mark the connection `Connected` —
+    /// but only if no other `connect`/`disconnect` call started after
+    /// this one did.
+    pub async fn connect<F, Fut>(&self, connect: F) -> Result<(), AppError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(), AppError>>,
+    {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let result = connect().await;
+
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return result;
+        }
+        if result.is_ok() {
+            *self.state.lock().unwrap() = ConnectionState::Connected;
+        }
+        result
+    }
+
+    /// Run `disconnect`, and mark the connection `Disconnected` — but
+    /// only if no other `connect`/`disconnect` call started after this
+    /// one did.
+    pub async fn disconnect<F, Fut>(&self, disconnect: F)
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        disconnect().await;
+
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        *self.state.lock().unwrap() = ConnectionState::Disconnected;
+    }
+
+    /// Apply one `auto_connect` policy ahead of a read, regardless of
+    /// which read entry point called this. Already `Connected`: no-op.
+    /// `Disconnected` with `auto_connect` enabled: transparently
+    /// [`Self::connect`]. `Disconnected` with `auto_connect` disabled:
+    /// fail with [`AppError::NotConnected`] rather than connecting
+    /// implicitly, so every caller that shares a `ConnectionSequencer`
+    /// sees the same behavior instead of each read path picking its own.
+    pub async fn ensure_connected_for_read<F, Fut>(&self, auto_connect: bool, connect: F) -> Result<(), AppError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(), AppError>>,
+    {
+        if self.state() == ConnectionState::Connected {
+            return Ok(());
+        }
+        if !auto_connect {
+            return Err(AppError::NotConnected);
+        }
+        self.connect(connect).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::{sleep, Duration};
+
+    #[tokio::test]
+    async fn a_slow_connect_does_not_override_a_disconnect_issued_after_it() {
+        let sequencer = ConnectionSequencer::new();
+
+        let slow_connect = sequencer.connect(|| async {
+            sleep(Duration::from_millis(30)).await;
+            Ok(())
+        });
+        let fast_disconnect = sequencer.disconnect(|| async {});
+
+        let (_, ()) = tokio::join!(slow_connect, fast_disconnect);
+
+        assert_eq!(sequencer.state(), ConnectionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn a_connect_with_no_competing_call_updates_state_normally() {
+        let sequencer = ConnectionSequencer::new();
+
+        sequencer.connect(|| async { Ok(()) }).await.unwrap();
+
+        assert_eq!(sequencer.state(), ConnectionState::Connected);
+    }
+
+    #[tokio::test]
+    async fn a_failed_connect_leaves_the_state_disconnected() {
+        let sequencer = ConnectionSequencer::new();
+
+        let result = sequencer.connect(|| async { Err(AppError::Io(std::io::Error::other("refused"))) }).await;
+
+        assert!(result.is_err());
+        assert_eq!(sequencer.state(), ConnectionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn ensure_connected_for_read_is_a_no_op_when_already_connected() {
+        let sequencer = ConnectionSequencer::new();
+        sequencer.connect(|| async { Ok(()) }).await.unwrap();
+
+        let result = sequencer
+            .ensure_connected_for_read(false, || async { panic!("should not reconnect when already connected") })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn ensure_connected_for_read_connects_automatically_when_enabled() {
+        let sequencer = ConnectionSequencer::new();
+
+        let result = sequencer.ensure_connected_for_read(true, || async { Ok(()) }).await;
+
+        assert!(result.is_ok());
+        assert_eq!(sequencer.state(), ConnectionState::Connected);
+    }
+
+    #[tokio::test]
+    async fn ensure_connected_for_read_rejects_a_disconnected_device_when_auto_connect_is_off() {
+        let sequencer = ConnectionSequencer::new();
+
+        let result = sequencer
+            .ensure_connected_for_read(false, || async { panic!("auto_connect is disabled, connect must not run") })
+            .await;
+
+        assert!(matches!(result, Err(AppError::NotConnected)));
+        assert_eq!(sequencer.state(), ConnectionState::Disconnected);
+    }
+}
@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// Running totals for a connection's read requests: how many were made,
+/// how many succeeded, and how long they took. Exposed to the frontend
+/// as a dashboard summary rather than raw per-request logs — there is no
+/// logging framework wired into this crate, so a request slow enough to
+/// warrant attention is surfaced the same way everything else here is:
+/// as a counter ([`ConnectionStats::slow_requests`]) the caller can poll
+/// or display, rather than a `warn!`-style log line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct ConnectionStats {
+    pub total_requests: u64,
+    pub successes: u64,
+    pub failures: u64,
+    total_duration_ms: u64,
+    pub max_duration_ms: u64,
+    /// How many recorded requests took longer than `slow_threshold_ms`.
+    pub slow_requests: u64,
+    slow_threshold_ms: u64,
+}
+
+impl ConnectionStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Like [`ConnectionStats::new`], but requests taking longer than
+    /// `slow_threshold_ms` are additionally counted in
+    /// [`ConnectionStats::slow_requests`], for spotting performance
+    /// regressions without needing debug-level logging turned on.
+    pub fn with_slow_threshold_ms(slow_threshold_ms: u64) -> Self {
+        Self {
+            slow_threshold_ms,
+            ..Self::default()
+        }
+    }
+
+    /// Record the outcome and elapsed time of one request.
+    pub fn record(&mut self, success: bool, duration: Duration) {
+        self.total_requests += 1;
+        if success {
+            self.successes += 1;
+        } else {
+            self.failures += 1;
+        }
+
+        let duration_ms = duration.as_millis() as u64;
+        self.total_duration_ms += duration_ms;
+        self.max_duration_ms = self.max_duration_ms.max(duration_ms);
+        if self.slow_threshold_ms != 0 && duration_ms > self.slow_threshold_ms {
+            self.slow_requests += 1;
+        }
+    }
+
+    /// Fraction of requests that succeeded, in `0.0..=1.0`. `None` before
+    /// any request has been recorded.
+    pub fn success_rate(&self) -> Option<f64> {
+        if self.total_requests == 0 {
+            return None;
+        }
+        Some(self.successes as f64 / self.total_requests as f64)
+    }
+
+    /// Mean response time across every recorded request, successful or
+    /// not. `None` before any request has been recorded.
+    pub fn average_duration_ms(&self) -> Option<f64> {
+        if self.total_requests == 0 {
+            return None;
+        }
+        Some(self.total_duration_ms as f64 / self.total_requests as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_fresh_tracker_has_no_rate_or_average_yet() {
+        let stats = ConnectionStats::new();
+
+        assert_eq!(stats.success_rate(), None);
+        assert_eq!(stats.average_duration_ms(), None);
+    }
+
+    #[test]
+    fn success_rate_reflects_the_recorded_mix() {
+        let mut stats = ConnectionStats::new();
+        stats.record(true, Duration::from_millis(10));
+        stats.record(true, Duration::from_millis(10));
+        stats.record(false, Duration::from_millis(10));
+
+        assert_eq!(stats.total_requests, 3);
+        assert_eq!(stats.success_rate(), Some(2.0 / 3.0));
+    }
+
+    #[test]
+    fn average_and_max_duration_are_tracked_across_every_request() {
+        let mut stats = ConnectionStats::new();
+        stats.record(true, Duration::from_millis(10));
+        stats.record(true, Duration::from_millis(30));
+
+        assert_eq!(stats.average_duration_ms(), Some(20.0));
+        assert_eq!(stats.max_duration_ms, 30);
+    }
+
+    #[test]
+    fn requests_past_the_slow_threshold_are_counted_separately() {
+        let mut stats = ConnectionStats::with_slow_threshold_ms(50);
+        stats.record(true, Duration::from_millis(10));
+        stats.record(true, Duration::from_millis(51));
+        stats.record(false, Duration::from_millis(200));
+
+        assert_eq!(stats.total_requests, 3);
+        assert_eq!(stats.slow_requests, 2);
+    }
+
+    #[test]
+    fn without_a_slow_threshold_no_request_is_ever_flagged_slow() {
+        let mut stats = ConnectionStats::new();
+        stats.record(true, Duration::from_secs(10));
+
+        assert_eq!(stats.slow_requests, 0);
+    }
+
+    #[test]
+    fn a_request_exactly_at_the_threshold_is_not_counted_as_slow() {
+        let mut stats = ConnectionStats::with_slow_threshold_ms(50);
+        stats.record(true, Duration::from_millis(50));
+
+        assert_eq!(stats.slow_requests, 0);
+    }
+
+    #[test]
+    fn a_failed_request_still_counts_toward_duration_and_total() {
+        let mut stats = ConnectionStats::new();
+        stats.record(false, Duration::from_millis(50));
+
+        assert_eq!(stats.total_requests, 1);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.success_rate(), Some(0.0));
+        assert_eq!(stats.average_duration_ms(), Some(50.0));
+    }
+}
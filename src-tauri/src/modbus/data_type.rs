@@ -0,0 +1,28 @@
+/// How a point's raw register(s) should be interpreted when producing
+/// an [`crate::modbus::AddressResult`]. Grown incrementally as new
+/// representations are supported.
+///
+/// There is no plain 32-bit integer variant yet — 32-bit encoding exists
+/// only on the write side ([`crate::modbus::encode_u32_to_registers`],
+/// [`crate::modbus::encode_i32_to_registers`]) and has no read-side
+/// counterpart wired into [`crate::modbus::create_address_result`] —
+/// and `parsed_value` is always decimal-rendered, never hex or binary.
+/// Tooling that wants a hex dump across arbitrary register widths
+/// should use [`crate::export::export_raw_hex`], which formats each
+/// register uniformly regardless of how many registers compose the
+/// logical value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataType {
+    /// A single boolean flag packed into one bit of a register, see
+    /// [`crate::modbus::PointConfig::bit_index`].
+    Bit,
+    /// An ASCII string packed two characters per register, see
+    /// [`crate::modbus::decode_ascii_string`].
+    String,
+    /// One register packed as 4 BCD digits, see
+    /// [`crate::modbus::decode_bcd16`].
+    Bcd16,
+    /// Two registers packed as 8 BCD digits, see
+    /// [`crate::modbus::decode_bcd32`].
+    Bcd32,
+}
@@ -0,0 +1,97 @@
+use super::{ByteOrder, WordOrder};
+
+/// Flatten a sequence of consecutive registers into their raw byte
+/// representation, honoring `order` for each register. Used whenever a
+/// multi-register value (strings, 32/64-bit numbers, ...) needs to be
+/// decoded from its constituent `u16` registers.
+pub fn registers_to_bytes(registers: &[u16], order: ByteOrder) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(registers.len() * 2);
+    for register in registers {
+        let [high, low] = register.to_be_bytes();
+        match order {
+            ByteOrder::BigEndian => {
+                bytes.push(high);
+                bytes.push(low);
+            }
+            ByteOrder::LittleEndian => {
+                bytes.push(low);
+                bytes.push(high);
+            }
+        }
+    }
+    bytes
+}
+
+/// Reorder registers so the most significant word comes first,
+/// undoing a device's word-swapped transmission order before the
+/// bytes are flattened and interpreted as a single wide value.
+fn reorder_words(registers: &[u16], word_order: WordOrder) -> Vec<u16> {
+    let mut words = registers.to_vec();
+    if word_order == WordOrder::LowFirst {
+        words.reverse();
+    }
+    words
+}
+
+/// Decode 4 consecutive registers as an IEEE 754 double-precision
+/// (`f64`) value, honoring `byte_order` for the layout within each
+/// register and `word_order` for which register holds the most
+/// significant bits.
+pub fn decode_f64(registers: &[u16; 4], byte_order: ByteOrder, word_order: WordOrder) -> f64 {
+    let words = reorder_words(registers, word_order);
+    let bytes = registers_to_bytes(&words, byte_order);
+    f64::from_be_bytes(bytes.try_into().expect("exactly 8 bytes from 4 registers"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_f64_from_four_registers_big_endian() {
+        let bytes = 1.5f64.to_be_bytes();
+        let registers = [
+            u16::from_be_bytes([bytes[0], bytes[1]]),
+            u16::from_be_bytes([bytes[2], bytes[3]]),
+            u16::from_be_bytes([bytes[4], bytes[5]]),
+            u16::from_be_bytes([bytes[6], bytes[7]]),
+        ];
+
+        assert_eq!(
+            decode_f64(&registers, ByteOrder::BigEndian, WordOrder::HighFirst),
+            1.5
+        );
+    }
+
+    #[test]
+    fn decodes_f64_with_swapped_word_order() {
+        let bytes = 1.5f64.to_be_bytes();
+        let high_first = [
+            u16::from_be_bytes([bytes[0], bytes[1]]),
+            u16::from_be_bytes([bytes[2], bytes[3]]),
+            u16::from_be_bytes([bytes[4], bytes[5]]),
+            u16::from_be_bytes([bytes[6], bytes[7]]),
+        ];
+        let mut low_first = high_first;
+        low_first.reverse();
+
+        assert_eq!(
+            decode_f64(&low_first, ByteOrder::BigEndian, WordOrder::LowFirst),
+            1.5
+        );
+    }
+
+    #[test]
+    fn flattens_consecutive_registers_in_order() {
+        let registers = [0x0102u16, 0x0304u16];
+
+        assert_eq!(
+            registers_to_bytes(&registers, ByteOrder::BigEndian),
+            vec![0x01, 0x02, 0x03, 0x04]
+        );
+        assert_eq!(
+            registers_to_bytes(&registers, ByteOrder::LittleEndian),
+            vec![0x02, 0x01, 0x04, 0x03]
+        );
+    }
+}
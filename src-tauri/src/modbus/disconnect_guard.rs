@@ -0,0 +1,122 @@
+use super::read_cancellation::ReadCancellationToken;
+
+/// Runs a cleanup closure exactly once when dropped, guaranteeing it
+/// fires even if the scope holding the connection exits early (error
+/// return, `?`, or panic unwind) rather than only on the "happy path"
+/// disconnect call.
+///
+/// Also cancels `cancel` at the same time: `disconnect`/`Drop` clearing
+/// a connection races an in-flight read that's still awaiting a
+/// response on it, which otherwise resolves against state that's
+/// already gone. Wiring the same [`ReadCancellationToken`] a read
+/// started with (via [`super::read_with_cancellation`]) into the guard
+/// that clears its connection makes disconnect wake that read with
+/// [`crate::error::AppError::Cancelled`] instead of racing it.
+pub struct DisconnectGuard<F: FnOnce()> {
+    cleanup: Option<F>,
+    cancel: ReadCancellationToken,
+}
+
+impl<F: FnOnce()> DisconnectGuard<F> {
+    pub fn new(cleanup: F, cancel: ReadCancellationToken) -> Self {
+        Self {
+            cleanup: Some(cleanup),
+            cancel,
+        }
+    }
+
+    /// Run the cleanup immediately and consume the guard, so it doesn't
+    /// run a second time on drop.
+    pub fn run_now(mut self) {
+        self.cancel.cancel();
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+}
+
+impl<F: FnOnce()> Drop for DisconnectGuard<F> {
+    fn drop(&mut self) {
+        self.cancel.cancel();
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::error::AppError;
+    use crate::modbus::read_with_cancellation;
+    use tokio::time::{sleep, Duration};
+
+    #[test]
+    fn cleanup_runs_when_guard_is_dropped() {
+        let ran = Rc::new(RefCell::new(false));
+        let ran_clone = ran.clone();
+
+        {
+            let _guard = DisconnectGuard::new(move || *ran_clone.borrow_mut() = true, ReadCancellationToken::new());
+        }
+
+        assert!(*ran.borrow());
+    }
+
+    #[test]
+    fn cleanup_runs_on_early_return_from_a_scope() {
+        let ran = Rc::new(RefCell::new(false));
+        let ran_clone = ran.clone();
+
+        fn early_return(flag: Rc<RefCell<bool>>, bail: bool) {
+            let _guard = DisconnectGuard::new(move || *flag.borrow_mut() = true, ReadCancellationToken::new());
+            if bail {
+                return;
+            }
+            unreachable!("test only calls this with bail = true");
+        }
+        early_return(ran_clone, true);
+
+        assert!(*ran.borrow());
+    }
+
+    #[test]
+    fn cleanup_does_not_run_twice_when_invoked_manually() {
+        let count = Rc::new(RefCell::new(0));
+        let count_clone = count.clone();
+
+        let guard = DisconnectGuard::new(move || *count_clone.borrow_mut() += 1, ReadCancellationToken::new());
+        guard.run_now();
+
+        assert_eq!(*count.borrow(), 1);
+    }
+
+    #[tokio::test]
+    async fn disconnecting_while_a_read_is_in_flight_cancels_it_without_panicking() {
+        let cancel = ReadCancellationToken::new();
+        let disconnected = Rc::new(RefCell::new(false));
+        let disconnected_clone = disconnected.clone();
+        let guard = DisconnectGuard::new(move || *disconnected_clone.borrow_mut() = true, cancel.clone());
+
+        let read = read_with_cancellation(&cancel, || async {
+            sleep(Duration::from_secs(10)).await;
+            Ok(vec![1])
+        });
+
+        // Give the read a moment to actually start waiting before the
+        // guard races it, the same way the request describes: a
+        // disconnect arriving mid-read, not before it.
+        let disconnect = async {
+            sleep(Duration::from_millis(10)).await;
+            guard.run_now();
+        };
+
+        let (result, ()) = tokio::join!(read, disconnect);
+
+        assert!(matches!(result, Err(AppError::Cancelled)));
+        assert!(*disconnected.borrow(), "cleanup must still run even though the read was cancelled");
+    }
+}
@@ -0,0 +1,86 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use tokio::net::{lookup_host, TcpStream};
+
+use crate::error::AppError;
+
+/// Connect to `host:port`, accepting a hostname resolved via async DNS
+/// (e.g. `"plc.local"`) as well as a bare IP — unlike
+/// [`super::probe_tcp_reachable`]'s synchronous resolver, this runs on
+/// the tokio reactor instead of blocking a worker thread. A literal IP
+/// still takes a fast path (parsed directly into a [`SocketAddr`]
+/// instead of round-tripping through the resolver), since most devices
+/// in this crate are addressed by IP ([`crate::config::ConnectionConfig::ip`]
+/// is validated as one). When DNS resolves `host` to multiple
+/// addresses, each is tried in order until one connects; a resolution
+/// failure is reported distinctly from a connect failure so the two
+/// don't read as the same problem.
+pub async fn connect_with_dns(host: &str, port: u16, timeout: Duration) -> Result<TcpStream, AppError> {
+    let addrs: Vec<SocketAddr> = match format!("{host}:{port}").parse() {
+        Ok(addr) => vec![addr],
+        Err(_) => lookup_host((host, port))
+            .await
+            .map_err(|_| AppError::InvalidConfig("无法解析主机名".to_string()))?
+            .collect(),
+    };
+
+    if addrs.is_empty() {
+        return Err(AppError::InvalidConfig("无法解析主机名".to_string()));
+    }
+
+    let mut last_err = None;
+    for addr in addrs {
+        match tokio::time::timeout(timeout, TcpStream::connect(addr)).await {
+            Ok(Ok(stream)) => return Ok(stream),
+            Ok(Err(err)) => last_err = Some(err),
+            Err(_) => last_err = Some(std::io::Error::new(std::io::ErrorKind::TimedOut, "connect timed out")),
+        }
+    }
+
+    Err(AppError::Io(last_err.expect("addrs is non-empty, so the loop runs at least once")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn a_literal_ip_connects_without_going_through_the_resolver() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let result = connect_with_dns("127.0.0.1", addr.port(), Duration::from_millis(500)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn localhost_resolves_and_connects_via_dns() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let result = connect_with_dns("localhost", addr.port(), Duration::from_millis(500)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn an_unresolvable_hostname_reports_a_distinct_error() {
+        let result = connect_with_dns("this.host.does.not.exist.invalid", 502, Duration::from_millis(500)).await;
+
+        assert_eq!(result.unwrap_err().to_string(), AppError::InvalidConfig("无法解析主机名".to_string()).to_string());
+    }
+
+    #[tokio::test]
+    async fn a_resolved_host_with_nothing_listening_reports_a_connect_error_not_a_dns_error() {
+        // 127.0.0.1 resolves fine; nothing is listening on this port.
+        let result = connect_with_dns("127.0.0.1", 1, Duration::from_millis(200)).await;
+
+        match result {
+            Err(AppError::Io(_)) => {}
+            other => panic!("expected a connect-level Io error, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::str::FromStr;
+
+use crate::error::AppError;
+
+use super::{ByteOrder, ReadOnlyGuard, WordOrder, WriteRequest};
+
+/// Encode a 32-bit value's 4 bytes into the 2 registers a "write
+/// multiple registers" request expects, honoring `byte_order` for the
+/// byte layout across the full 4-byte buffer and `word_order` for
+/// which of the 2 resulting registers holds the most significant bits.
+fn bytes_to_registers(mut bytes: [u8; 4], byte_order: ByteOrder, word_order: WordOrder) -> [u16; 2] {
+    if byte_order == ByteOrder::LittleEndian {
+        bytes.reverse();
+    }
+    let mut registers = [
+        u16::from_be_bytes([bytes[0], bytes[1]]),
+        u16::from_be_bytes([bytes[2], bytes[3]]),
+    ];
+    if word_order == WordOrder::LowFirst {
+        registers.reverse();
+    }
+    registers
+}
+
+pub fn encode_u32_to_registers(value: u32, byte_order: ByteOrder, word_order: WordOrder) -> [u16; 2] {
+    bytes_to_registers(value.to_be_bytes(), byte_order, word_order)
+}
+
+pub fn encode_i32_to_registers(value: i32, byte_order: ByteOrder, word_order: WordOrder) -> [u16; 2] {
+    bytes_to_registers(value.to_be_bytes(), byte_order, word_order)
+}
+
+pub fn encode_f32_to_registers(value: f32, byte_order: ByteOrder, word_order: WordOrder) -> [u16; 2] {
+    bytes_to_registers(value.to_be_bytes(), byte_order, word_order)
+}
+
+/// Parse `value` as `data_type` (`"int16"`, `"uint16"`, `"int32"`,
+/// `"uint32"`, or `"float32"`) and build the [`WriteRequest`] that
+/// writes it starting at `start` — symmetric to
+/// [`super::create_address_result`]'s parsing on the read side. 32-bit
+/// types split into 2 registers via [`encode_i32_to_registers`] /
+/// [`encode_u32_to_registers`] / [`encode_f32_to_registers`] using
+/// [`ByteOrder::BigEndian`]/[`WordOrder::HighFirst`], matching this
+/// crate's existing default big-endian convention elsewhere (e.g.
+/// [`super::decode_bcd32`]); 16-bit types write a single register
+/// directly. An unparsable `value` or unrecognized `data_type` returns
+/// [`AppError::InvalidConfig`] instead of panicking.
+pub fn write_typed_value(guard: &ReadOnlyGuard, start: u16, value: &str, data_type: &str) -> Result<WriteRequest, AppError> {
+    let parse_error = |parsed_type: &str, error: std::num::ParseIntError| {
+        AppError::InvalidConfig(format!("无法将 \"{value}\" 解析为 {parsed_type}：{error}"))
+    };
+
+    let registers = match data_type {
+        "int16" => vec![i16::from_str(value).map_err(|e| parse_error("int16", e))? as u16],
+        "uint16" => vec![u16::from_str(value).map_err(|e| parse_error("uint16", e))?],
+        "int32" => {
+            let parsed = i32::from_str(value).map_err(|e| parse_error("int32", e))?;
+            encode_i32_to_registers(parsed, ByteOrder::BigEndian, WordOrder::HighFirst).to_vec()
+        }
+        "uint32" => {
+            let parsed = u32::from_str(value).map_err(|e| parse_error("uint32", e))?;
+            encode_u32_to_registers(parsed, ByteOrder::BigEndian, WordOrder::HighFirst).to_vec()
+        }
+        "float32" => {
+            let parsed = f32::from_str(value)
+                .map_err(|e| AppError::InvalidConfig(format!("无法将 \"{value}\" 解析为 float32：{e}")))?;
+            encode_f32_to_registers(parsed, ByteOrder::BigEndian, WordOrder::HighFirst).to_vec()
+        }
+        other => return Err(AppError::InvalidConfig(format!("不支持的 data_type \"{other}\""))),
+    };
+
+    WriteRequest::new(guard, start, registers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_u32_in_big_endian() {
+        assert_eq!(
+            encode_u32_to_registers(0x0102_0304, ByteOrder::BigEndian, WordOrder::HighFirst),
+            [0x0102, 0x0304]
+        );
+    }
+
+    #[test]
+    fn encodes_u32_in_little_endian() {
+        assert_eq!(
+            encode_u32_to_registers(0x0102_0304, ByteOrder::LittleEndian, WordOrder::HighFirst),
+            [0x0403, 0x0201]
+        );
+    }
+
+    #[test]
+    fn encodes_u32_with_swapped_word_order() {
+        assert_eq!(
+            encode_u32_to_registers(0x0102_0304, ByteOrder::BigEndian, WordOrder::LowFirst),
+            [0x0304, 0x0102]
+        );
+    }
+
+    #[test]
+    fn encodes_negative_i32() {
+        assert_eq!(
+            encode_i32_to_registers(-1, ByteOrder::BigEndian, WordOrder::HighFirst),
+            [0xFFFF, 0xFFFF]
+        );
+    }
+
+    #[test]
+    fn encodes_f32_matching_ieee754_bit_pattern() {
+        let registers = encode_f32_to_registers(1.0, ByteOrder::BigEndian, WordOrder::HighFirst);
+        assert_eq!(registers, [0x3F80, 0x0000]);
+    }
+
+    #[test]
+    fn write_typed_value_writes_a_single_register_for_uint16() {
+        let guard = ReadOnlyGuard::new();
+        let request = write_typed_value(&guard, 10, "42", "uint16").unwrap();
+        assert_eq!(request.start, 10);
+        assert_eq!(request.values, vec![42]);
+    }
+
+    #[test]
+    fn write_typed_value_writes_two_big_endian_registers_for_float32() {
+        let guard = ReadOnlyGuard::new();
+        let request = write_typed_value(&guard, 0, "1.0", "float32").unwrap();
+        assert_eq!(request.values, vec![0x3F80, 0x0000]);
+    }
+
+    #[test]
+    fn write_typed_value_writes_two_registers_for_int32_and_uint32() {
+        let guard = ReadOnlyGuard::new();
+        assert_eq!(write_typed_value(&guard, 0, "-1", "int32").unwrap().values, vec![0xFFFF, 0xFFFF]);
+        // 16909060 decimal == 0x0102_0304.
+        assert_eq!(write_typed_value(&guard, 0, "16909060", "uint32").unwrap().values, vec![0x0102, 0x0304]);
+    }
+
+    #[test]
+    fn write_typed_value_returns_a_config_error_instead_of_panicking_on_unparsable_input() {
+        let guard = ReadOnlyGuard::new();
+        let err = write_typed_value(&guard, 0, "abc", "float32").unwrap_err();
+        assert!(matches!(err, AppError::InvalidConfig(_)));
+    }
+
+    #[test]
+    fn write_typed_value_rejects_an_unrecognized_data_type() {
+        let guard = ReadOnlyGuard::new();
+        let err = write_typed_value(&guard, 0, "1", "float64").unwrap_err();
+        assert!(matches!(err, AppError::InvalidConfig(_)));
+    }
+
+    #[test]
+    fn write_typed_value_is_rejected_in_read_only_mode() {
+        let guard = ReadOnlyGuard::new();
+        guard.set_read_only(true);
+
+        let err = write_typed_value(&guard, 0, "1", "uint16").unwrap_err();
+
+        assert!(matches!(err, AppError::WriteForbidden));
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+/// A distinct connection-failure message and how often it has recurred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregatedError {
+    pub message: String,
+    pub count: u32,
+    pub first_at: DateTime<Utc>,
+    pub last_at: DateTime<Utc>,
+}
+
+/// Deduplicates repeated connection-failure messages so a flapping
+/// device doesn't flood the log with the same line thousands of times.
+/// The first occurrence of a message is reported as new; subsequent
+/// occurrences just bump the running count.
+#[derive(Default)]
+pub struct ErrorAggregator {
+    entries: HashMap<String, AggregatedError>,
+}
+
+impl ErrorAggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an occurrence of `message`. Returns `true` if this is the
+    /// first time this exact message has been seen (i.e. it should be
+    /// logged), `false` if it was deduplicated against an existing entry.
+    pub fn record(&mut self, message: &str, at: DateTime<Utc>) -> bool {
+        match self.entries.get_mut(message) {
+            Some(entry) => {
+                entry.count += 1;
+                entry.last_at = at;
+                false
+            }
+            None => {
+                self.entries.insert(
+                    message.to_string(),
+                    AggregatedError {
+                        message: message.to_string(),
+                        count: 1,
+                        first_at: at,
+                        last_at: at,
+                    },
+                );
+                true
+            }
+        }
+    }
+
+    /// Snapshot of every distinct error and its recurrence count.
+    pub fn summary(&self) -> Vec<AggregatedError> {
+        self.entries.values().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn repeated_message_is_deduplicated_but_counted() {
+        let mut aggregator = ErrorAggregator::new();
+
+        assert!(aggregator.record("connection refused", at(0)));
+        assert!(!aggregator.record("connection refused", at(1)));
+        assert!(!aggregator.record("connection refused", at(2)));
+
+        let summary = aggregator.summary();
+        assert_eq!(summary.len(), 1);
+        assert_eq!(summary[0].count, 3);
+        assert_eq!(summary[0].first_at, at(0));
+        assert_eq!(summary[0].last_at, at(2));
+    }
+
+    #[test]
+    fn distinct_messages_are_tracked_separately() {
+        let mut aggregator = ErrorAggregator::new();
+
+        aggregator.record("connection refused", at(0));
+        aggregator.record("timeout", at(1));
+
+        assert_eq!(aggregator.summary().len(), 2);
+    }
+}
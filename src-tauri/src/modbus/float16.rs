@@ -0,0 +1,45 @@
+/// Decode an IEEE 754 half-precision (`binary16`) value, as carried in a
+/// single Modbus register, into an `f32`. Implemented by hand since the
+/// crate otherwise has no need for a dedicated `half` dependency.
+pub fn decode_f16(bits: u16) -> f32 {
+    let sign = (bits >> 15) & 0x1;
+    let exponent = (bits >> 10) & 0x1F;
+    let mantissa = bits & 0x3FF;
+
+    let value = match exponent {
+        0 if mantissa == 0 => 0.0,
+        // Subnormal: no implicit leading 1 bit.
+        0 => (mantissa as f32) * 2f32.powi(-24),
+        0x1F if mantissa == 0 => f32::INFINITY,
+        0x1F => f32::NAN,
+        _ => {
+            let normalized_mantissa = 1.0 + (mantissa as f32) / 1024.0;
+            normalized_mantissa * 2f32.powi(exponent as i32 - 15)
+        }
+    };
+
+    if sign == 1 {
+        -value
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_known_half_precision_values() {
+        assert_eq!(decode_f16(0x3C00), 1.0);
+        assert_eq!(decode_f16(0xC000), -2.0);
+        assert_eq!(decode_f16(0x0000), 0.0);
+        assert_eq!(decode_f16(0x8000), -0.0);
+    }
+
+    #[test]
+    fn decodes_special_values() {
+        assert!(decode_f16(0x7C00).is_infinite());
+        assert!(decode_f16(0x7E00).is_nan());
+    }
+}
@@ -0,0 +1,98 @@
+/// A run of registers that looks like a device-side gap rather than
+/// real data, as reported by [`detect_suspicious_holes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuspiciousHole {
+    /// Index into the scanned slice where the run starts.
+    pub start_offset: usize,
+    pub len: usize,
+}
+
+/// Scan `registers` for runs of at least `min_run` consecutive
+/// identical placeholder values (`0x0000` or `0xFFFF`) — the values
+/// some devices return for an address range they don't actually back,
+/// rather than erroring. Each such run is reported so the caller can
+/// flag that stretch of an otherwise-contiguous range as suspicious.
+pub fn detect_suspicious_holes(registers: &[u16], min_run: usize) -> Vec<SuspiciousHole> {
+    let mut holes = Vec::new();
+    let mut run: Option<(u16, usize)> = None;
+
+    for (i, &value) in registers.iter().enumerate() {
+        let is_placeholder = value == 0x0000 || value == 0xFFFF;
+        match run {
+            Some((run_value, _)) if is_placeholder && value == run_value => {}
+            _ => {
+                if let Some((_, start)) = run.take() {
+                    if i - start >= min_run {
+                        holes.push(SuspiciousHole {
+                            start_offset: start,
+                            len: i - start,
+                        });
+                    }
+                }
+                if is_placeholder {
+                    run = Some((value, i));
+                }
+            }
+        }
+    }
+
+    if let Some((_, start)) = run {
+        if registers.len() - start >= min_run {
+            holes.push(SuspiciousHole {
+                start_offset: start,
+                len: registers.len() - start,
+            });
+        }
+    }
+
+    holes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_run_of_0xffff_in_the_middle_is_flagged_as_a_suspicious_hole() {
+        let registers = [1, 2, 3, 0xFFFF, 0xFFFF, 0xFFFF, 4, 5];
+
+        let holes = detect_suspicious_holes(&registers, 2);
+
+        assert_eq!(
+            holes,
+            vec![SuspiciousHole {
+                start_offset: 3,
+                len: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn a_run_shorter_than_min_run_is_not_flagged() {
+        let registers = [1, 0x0000, 2, 3];
+
+        let holes = detect_suspicious_holes(&registers, 2);
+
+        assert!(holes.is_empty());
+    }
+
+    #[test]
+    fn ordinary_non_repeating_values_are_never_flagged() {
+        let registers = [1, 2, 3, 4, 5];
+
+        let holes = detect_suspicious_holes(&registers, 2);
+
+        assert!(holes.is_empty());
+    }
+
+    #[test]
+    fn a_single_placeholder_value_that_is_not_repeated_is_not_a_run() {
+        // 0x0000 and 0xFFFF next to each other are two different
+        // placeholder values, not one contiguous run.
+        let registers = [0x0000, 0xFFFF, 0x0000];
+
+        let holes = detect_suspicious_holes(&registers, 2);
+
+        assert!(holes.is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+use std::future::Future;
+
+use crate::error::AppError;
+
+use super::range_optimizer::MAX_REGISTERS_PER_REQUEST;
+use super::AddressRange;
+
+/// A failure from one segment of a range split by [`read_large_range`],
+/// identifying which segment (0-based, in split order) it was so the
+/// caller knows exactly how far the read got before failing.
+#[derive(Debug)]
+pub struct SegmentReadError {
+    pub segment_index: usize,
+    pub segment: AddressRange,
+    pub source: AppError,
+}
+
+/// Read `range` in full, splitting it into chunks of at most 125
+/// registers if it exceeds the protocol's per-request limit (rather
+/// than being rejected outright, as [`super::validate_ranges`] would
+/// otherwise require) and concatenating their registers back into one
+/// result. A range within the limit is still read in a single call.
+pub async fn read_large_range<F, Fut>(range: AddressRange, mut read_segment: F) -> Result<Vec<u16>, SegmentReadError>
+where
+    F: FnMut(AddressRange) -> Fut,
+    Fut: Future<Output = Result<Vec<u16>, AppError>>,
+{
+    let mut values = Vec::with_capacity(range.count as usize);
+    let mut offset = 0;
+    let mut segment_index = 0;
+
+    while offset < range.count {
+        let count = (range.count - offset).min(MAX_REGISTERS_PER_REQUEST);
+        let segment = AddressRange {
+            start: range.start + offset,
+            count,
+            slave_id: range.slave_id,
+        };
+
+        match read_segment(segment).await {
+            Ok(mut registers) => values.append(&mut registers),
+            Err(source) => {
+                return Err(SegmentReadError {
+                    segment_index,
+                    segment,
+                    source,
+                });
+            }
+        }
+
+        offset += count;
+        segment_index += 1;
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u16, count: u16) -> AddressRange {
+        AddressRange { start, count, slave_id: None }
+    }
+
+    #[tokio::test]
+    async fn a_range_within_the_limit_is_read_in_a_single_segment() {
+        let mut calls = 0;
+
+        let result = read_large_range(range(0, 100), |segment| {
+            calls += 1;
+            async move { Ok(vec![0; segment.count as usize]) }
+        })
+        .await;
+
+        assert_eq!(calls, 1);
+        assert_eq!(result.unwrap().len(), 100);
+    }
+
+    #[tokio::test]
+    async fn a_range_of_300_splits_into_three_segments_and_concatenates() {
+        let mut segments = Vec::new();
+
+        let result = read_large_range(range(0, 300), |segment| {
+            segments.push(segment);
+            async move { Ok(vec![segment.start; segment.count as usize]) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments.iter().map(|s| s.count).collect::<Vec<_>>(), vec![125, 125, 50]);
+        assert_eq!(result.len(), 300);
+    }
+
+    #[tokio::test]
+    async fn a_failing_middle_segment_reports_its_index() {
+        let mut calls = 0;
+
+        let result = read_large_range(range(0, 300), |segment| {
+            let call = calls;
+            calls += 1;
+            async move {
+                if call == 1 {
+                    Err(AppError::InvalidConfig("设备无响应".to_string()))
+                } else {
+                    Ok(vec![0; segment.count as usize])
+                }
+            }
+        })
+        .await;
+
+        let err = result.unwrap_err();
+        assert_eq!(err.segment_index, 1);
+        assert_eq!(err.segment.start, 125);
+    }
+}
@@ -0,0 +1,39 @@
+/// Apply `y = k*x + b` engineering-unit scaling to a raw value, as
+/// configured per point (see [`super::PointConfig`]). `scale` and
+/// `offset` default to the identity transform (`1.0`/`0.0`) when unset,
+/// and the result is rounded to `precision` decimal places when given.
+pub fn linear_scale(raw: f64, scale: Option<f64>, offset: Option<f64>, precision: Option<u32>) -> f64 {
+    let scaled = raw * scale.unwrap_or(1.0) + offset.unwrap_or(0.0);
+    match precision {
+        Some(precision) => {
+            let factor = 10f64.powi(precision as i32);
+            (scaled * factor).round() / factor
+        }
+        None => scaled,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_4000_with_gain_0_01_converts_to_40_degrees() {
+        assert_eq!(linear_scale(4000.0, Some(0.01), None, None), 40.0);
+    }
+
+    #[test]
+    fn an_offset_shifts_the_scaled_value() {
+        assert_eq!(linear_scale(10.0, Some(2.0), Some(3.0), None), 23.0);
+    }
+
+    #[test]
+    fn no_scale_or_offset_is_the_identity_transform() {
+        assert_eq!(linear_scale(7.5, None, None, None), 7.5);
+    }
+
+    #[test]
+    fn precision_rounds_to_the_requested_number_of_decimals() {
+        assert_eq!(linear_scale(1.0, Some(1.0 / 3.0), None, Some(2)), 0.33);
+    }
+}
@@ -0,0 +1,89 @@
+//! Modbus protocol primitives: address ranges and the validation rules
+//! shared by every read/write path.
+
+mod address_range;
+mod address_result;
+mod ascii_string;
+mod bcd;
+mod bitfield;
+mod bits;
+mod connection_sequencer;
+mod connection_stats;
+mod data_type;
+mod decode;
+mod disconnect_guard;
+mod dns_connect;
+mod encode32;
+mod error_log;
+mod float16;
+mod hole_detection;
+mod large_range;
+mod linear_scale;
+mod lock;
+mod pipeline;
+mod point_config;
+mod qos;
+mod quality;
+mod range_optimizer;
+mod read_cancellation;
+mod read_history;
+mod read_only_guard;
+mod read_timeout;
+mod reconnect;
+mod register_kind;
+mod retry;
+mod rtu;
+mod rtu_over_tcp;
+mod scheduler;
+mod slave_routing;
+mod socket_tuning;
+mod tcp_probe;
+mod timeout_profile;
+mod types;
+mod unit_conversion;
+mod write;
+
+pub use address_range::{is_valid_slave_id, validate_bit_read_count, validate_ranges, AddressRange, RangeError, MAX_BIT_READ_COUNT};
+pub use address_result::{create_address_result, AddressResult};
+pub use ascii_string::decode_ascii_string;
+pub use bcd::{decode_bcd16, decode_bcd32};
+pub use bitfield::concat_bits;
+pub use bits::{read_coils, read_discrete_inputs, unpack_bits};
+pub use connection_sequencer::{ConnectionSequencer, ConnectionState};
+pub use connection_stats::ConnectionStats;
+pub use data_type::DataType;
+pub use decode::{decode_f64, registers_to_bytes};
+pub use disconnect_guard::DisconnectGuard;
+pub use dns_connect::connect_with_dns;
+pub use encode32::{encode_f32_to_registers, encode_i32_to_registers, encode_u32_to_registers, write_typed_value};
+pub use error_log::{AggregatedError, ErrorAggregator};
+pub use float16::decode_f16;
+pub use hole_detection::{detect_suspicious_holes, SuspiciousHole};
+pub use large_range::{read_large_range, SegmentReadError};
+pub use linear_scale::linear_scale;
+pub use lock::ConnectionLock;
+pub use pipeline::{
+    read_ranges_detailed, read_ranges_detailed_by_kind, read_ranges_detailed_with_concurrency_limit,
+    read_ranges_pipelined,
+};
+pub use point_config::{generate_csv_header, PointConfig};
+pub use qos::{sort_by_qos, QosLevel, QueuedRead};
+pub use quality::Quality;
+pub use range_optimizer::{optimize_ranges, MergedRange};
+pub use read_cancellation::{read_with_cancellation, ReadCancellationToken};
+pub use read_history::{ReadHistory, ReadHistoryEntry};
+pub use read_only_guard::ReadOnlyGuard;
+pub use read_timeout::read_with_timeout;
+pub use reconnect::{ensure_connected, ReconnectPolicy};
+pub use register_kind::{RegisterKind, TypedAddressRange};
+pub use retry::{read_with_retry, RetryPolicy};
+pub use rtu::{detect_baud_rate, COMMON_BAUD_RATES};
+pub use rtu_over_tcp::{crc16_modbus, decode_rtu_frame, encode_rtu_frame};
+pub use scheduler::{ModbusReader, Scheduler};
+pub use slave_routing::read_ranges_with_slave_routing;
+pub use socket_tuning::{apply_socket_buffer_sizes, SocketBufferSizes};
+pub use tcp_probe::probe_tcp_reachable;
+pub use timeout_profile::DeviceTimeoutProfile;
+pub use types::{ByteOrder, WordOrder};
+pub use unit_conversion::{load_unit_conversions, UnitConversion, UnitConversionTable};
+pub use write::{WriteCoilRequest, WriteRequest};
@@ -0,0 +1,397 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::error::AppError;
+
+use super::{AddressRange, RegisterKind, TypedAddressRange};
+
+/// One range's outcome from [`read_ranges_detailed`]: either the
+/// registers it read, or the error that range's read hit.
+type DetailedRangeResult = (AddressRange, Result<Vec<u16>, AppError>);
+
+/// Read every range in `ranges` by submitting all the requests up
+/// front rather than waiting for each response before issuing the
+/// next, which hides per-request latency behind the rest of the batch
+/// on high-latency links. Results are returned in the same order as
+/// `ranges`, regardless of which request actually completes first.
+pub async fn read_ranges_pipelined<F, Fut>(
+    ranges: Vec<AddressRange>,
+    read_range: F,
+) -> Vec<(AddressRange, Vec<u16>)>
+where
+    F: Fn(AddressRange) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Vec<u16>> + Send + 'static,
+{
+    let mut pending = JoinSet::new();
+    for (index, range) in ranges.iter().copied().enumerate() {
+        let read_range = read_range.clone();
+        pending.spawn(async move { (index, range, read_range(range).await) });
+    }
+
+    let mut results: Vec<Option<(AddressRange, Vec<u16>)>> = vec![None; ranges.len()];
+    while let Some(outcome) = pending.join_next().await {
+        let (index, range, values) = outcome.expect("pipelined read task panicked");
+        results[index] = Some((range, values));
+    }
+
+    results
+        .into_iter()
+        .map(|result| result.expect("every spawned index is filled before join_next exhausts"))
+        .collect()
+}
+
+/// Read every range in `ranges`, same as [`read_ranges_pipelined`], but
+/// without letting one range's failure discard every other range's
+/// result: each range's outcome is reported independently, so a
+/// multi-range poll can surface partial results instead of an
+/// all-or-nothing failure.
+///
+/// `bad_value`, if given, flags a likely device fault: a device that
+/// has dropped off the bus often still answers, but with every
+/// register stuck at the same marker value (commonly `0xFFFF` or
+/// `0x0000`). When a range's read succeeds but every returned register
+/// equals `bad_value`, that range's result is downgraded to
+/// [`AppError::Modbus`] instead of being reported as valid data, so a
+/// sensor going offline is caught here rather than silently recorded.
+/// `None` disables the check.
+pub async fn read_ranges_detailed<F, Fut>(
+    ranges: Vec<AddressRange>,
+    read_range: F,
+    bad_value: Option<u16>,
+) -> Vec<DetailedRangeResult>
+where
+    F: Fn(AddressRange) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Result<Vec<u16>, AppError>> + Send + 'static,
+{
+    let mut pending = JoinSet::new();
+    for (index, range) in ranges.iter().copied().enumerate() {
+        let read_range = read_range.clone();
+        pending.spawn(async move { (index, range, read_range(range).await) });
+    }
+
+    let mut results: Vec<Option<DetailedRangeResult>> = std::iter::repeat_with(|| None).take(ranges.len()).collect();
+    while let Some(outcome) = pending.join_next().await {
+        let (index, range, read_result) = outcome.expect("pipelined read task panicked");
+        let read_result = match (read_result, bad_value) {
+            (Ok(values), Some(bad)) if !values.is_empty() && values.iter().all(|value| *value == bad) => {
+                Err(AppError::Modbus("疑似无效数据".to_string()))
+            }
+            (read_result, _) => read_result,
+        };
+        results[index] = Some((range, read_result));
+    }
+
+    results
+        .into_iter()
+        .map(|result| result.expect("every spawned index is filled before join_next exhausts"))
+        .collect()
+}
+
+/// Read every range in `ranges`, same as [`read_ranges_detailed`], but
+/// capped to at most `max_concurrency` reads in flight at once via a
+/// [`Semaphore`]. [`read_ranges_detailed`] submits every range's read up
+/// front unconditionally, which is fine when `read_range` shares one
+/// already-open connection (the extra requests just queue up behind it
+/// anyway), but is unsafe to call unbounded when `read_range` opens a
+/// fresh connection per range (e.g. a connection-pooled reader), since
+/// nothing then limits how many sockets get opened at once. Order is
+/// preserved the same way: results come back indexed to `ranges`,
+/// regardless of completion order, and one range's failure doesn't
+/// affect any other's result.
+pub async fn read_ranges_detailed_with_concurrency_limit<F, Fut>(
+    ranges: Vec<AddressRange>,
+    max_concurrency: usize,
+    read_range: F,
+) -> Vec<DetailedRangeResult>
+where
+    F: Fn(AddressRange) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Result<Vec<u16>, AppError>> + Send + 'static,
+{
+    let semaphore = Arc::new(Semaphore::new(max_concurrency.max(1)));
+    let mut pending = JoinSet::new();
+    for (index, range) in ranges.iter().copied().enumerate() {
+        let read_range = read_range.clone();
+        let semaphore = semaphore.clone();
+        pending.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.expect("concurrency-limit semaphore is never closed");
+            (index, range, read_range(range).await)
+        });
+    }
+
+    let mut results: Vec<Option<DetailedRangeResult>> = std::iter::repeat_with(|| None).take(ranges.len()).collect();
+    while let Some(outcome) = pending.join_next().await {
+        let (index, range, read_result) = outcome.expect("pipelined read task panicked");
+        results[index] = Some((range, read_result));
+    }
+
+    results
+        .into_iter()
+        .map(|result| result.expect("every spawned index is filled before join_next exhausts"))
+        .collect()
+}
+
+/// Read every range in `ranges`, same as [`read_ranges_detailed`], but
+/// each range carries its own [`RegisterKind`] so a single batch can mix
+/// holding/input registers with coils/discrete inputs instead of every
+/// range in the call going through the same function code. `read_registers`
+/// is used for [`RegisterKind::Holding`]/[`RegisterKind::Input`] ranges,
+/// `read_bits` for [`RegisterKind::Coil`]/[`RegisterKind::DiscreteInput`]
+/// ones; both receive the range's kind so they can pick the matching
+/// Modbus function code. Bit results are converted to `0`/`1` register
+/// values so every range's outcome comes back through the same `Vec<u16>`
+/// shape regardless of which table it was read from.
+pub async fn read_ranges_detailed_by_kind<RR, RRFut, RB, RBFut>(
+    ranges: Vec<TypedAddressRange>,
+    read_registers: RR,
+    read_bits: RB,
+) -> Vec<DetailedRangeResult>
+where
+    RR: Fn(AddressRange, RegisterKind) -> RRFut + Clone + Send + 'static,
+    RRFut: Future<Output = Result<Vec<u16>, AppError>> + Send + 'static,
+    RB: Fn(AddressRange, RegisterKind) -> RBFut + Clone + Send + 'static,
+    RBFut: Future<Output = Result<Vec<bool>, AppError>> + Send + 'static,
+{
+    let mut pending = JoinSet::new();
+    for (index, typed_range) in ranges.iter().copied().enumerate() {
+        let TypedAddressRange { range, register_kind } = typed_range;
+        let read_registers = read_registers.clone();
+        let read_bits = read_bits.clone();
+        pending.spawn(async move {
+            let result = if register_kind.is_bit_addressed() {
+                read_bits(range, register_kind)
+                    .await
+                    .map(|bits| bits.into_iter().map(|bit| bit as u16).collect())
+            } else {
+                read_registers(range, register_kind).await
+            };
+            (index, range, result)
+        });
+    }
+
+    let mut results: Vec<Option<DetailedRangeResult>> = std::iter::repeat_with(|| None).take(ranges.len()).collect();
+    while let Some(outcome) = pending.join_next().await {
+        let (index, range, read_result) = outcome.expect("pipelined read task panicked");
+        results[index] = Some((range, read_result));
+    }
+
+    results
+        .into_iter()
+        .map(|result| result.expect("every spawned index is filled before join_next exhausts"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn ranges(count: u16) -> Vec<AddressRange> {
+        (0..count)
+            .map(|i| AddressRange {
+                start: i * 10,
+                count: 1,
+                slave_id: None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn pipelined_reads_overlap_instead_of_serializing_latency() {
+        const DELAY_MS: u64 = 30;
+        const BATCH_SIZE: u16 = 5;
+
+        let started = Instant::now();
+        let results = read_ranges_pipelined(ranges(BATCH_SIZE), |range| async move {
+            tokio::time::sleep(Duration::from_millis(DELAY_MS)).await;
+            vec![range.start]
+        })
+        .await;
+        let pipelined_elapsed = started.elapsed();
+
+        assert_eq!(results.len(), BATCH_SIZE as usize);
+        // Serial execution would take roughly DELAY_MS * BATCH_SIZE;
+        // pipelined execution should finish in roughly one DELAY_MS.
+        assert!(
+            pipelined_elapsed < Duration::from_millis(DELAY_MS * BATCH_SIZE as u64 / 2),
+            "pipelined batch took {pipelined_elapsed:?}, expected well under serial time"
+        );
+    }
+
+    #[tokio::test]
+    async fn results_preserve_the_requested_range_order() {
+        let results = read_ranges_pipelined(ranges(3), |range| async move { vec![range.start] }).await;
+
+        let starts: Vec<u16> = results.iter().map(|(range, _)| range.start).collect();
+        assert_eq!(starts, vec![0, 10, 20]);
+    }
+
+    #[tokio::test]
+    async fn one_failing_range_does_not_discard_the_others_results() {
+        let results = read_ranges_detailed(
+            ranges(3),
+            |range| async move {
+                if range.start == 10 {
+                    Err(AppError::InvalidConfig("读取超时".to_string()))
+                } else {
+                    Ok(vec![range.start])
+                }
+            },
+            None,
+        )
+        .await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_err());
+        assert!(results[2].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn all_ranges_succeeding_returns_every_value() {
+        let results = read_ranges_detailed(ranges(2), |range| async move { Ok(vec![range.start]) }, None).await;
+
+        let values: Vec<u16> = results.into_iter().map(|(_, result)| result.unwrap()[0]).collect();
+        assert_eq!(values, vec![0, 10]);
+    }
+
+    #[tokio::test]
+    async fn a_range_returning_only_the_bad_value_marker_is_downgraded_to_an_error() {
+        let results = read_ranges_detailed(
+            ranges(2),
+            |range| async move {
+                if range.start == 0 {
+                    Ok(vec![0xFFFF, 0xFFFF])
+                } else {
+                    Ok(vec![range.start])
+                }
+            },
+            Some(0xFFFF),
+        )
+        .await;
+
+        assert!(results[0].1.is_err());
+        assert!(results[1].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn a_range_with_only_some_registers_at_the_bad_value_is_left_as_a_success() {
+        let results = read_ranges_detailed(ranges(1), |_range| async move { Ok(vec![0xFFFF, 0x0012]) }, Some(0xFFFF)).await;
+
+        assert!(results[0].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn without_a_bad_value_marker_the_check_is_disabled() {
+        let results = read_ranges_detailed(ranges(1), |_range| async move { Ok(vec![0xFFFF, 0xFFFF]) }, None).await;
+
+        assert!(results[0].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn concurrency_limit_caps_how_many_reads_are_in_flight_at_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        const MAX_CONCURRENCY: usize = 2;
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let in_flight_for_read = in_flight.clone();
+        let peak_for_read = peak_in_flight.clone();
+        let results = read_ranges_detailed_with_concurrency_limit(ranges(6), MAX_CONCURRENCY, move |range| {
+            let in_flight = in_flight_for_read.clone();
+            let peak = peak_for_read.clone();
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(vec![range.start])
+            }
+        })
+        .await;
+
+        assert_eq!(results.len(), 6);
+        assert!(
+            peak_in_flight.load(Ordering::SeqCst) <= MAX_CONCURRENCY,
+            "peak concurrency exceeded the configured limit"
+        );
+    }
+
+    #[tokio::test]
+    async fn concurrency_limited_results_preserve_the_requested_range_order() {
+        let results = read_ranges_detailed_with_concurrency_limit(ranges(3), 1, |range| async move { Ok(vec![range.start]) }).await;
+
+        let starts: Vec<u16> = results.iter().map(|(range, _)| range.start).collect();
+        assert_eq!(starts, vec![0, 10, 20]);
+    }
+
+    #[tokio::test]
+    async fn a_failing_range_does_not_affect_other_ranges_under_a_concurrency_limit() {
+        let results = read_ranges_detailed_with_concurrency_limit(ranges(3), 2, |range| async move {
+            if range.start == 10 {
+                Err(AppError::InvalidConfig("连接失败".to_string()))
+            } else {
+                Ok(vec![range.start])
+            }
+        })
+        .await;
+
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_err());
+        assert!(results[2].1.is_ok());
+    }
+
+    fn typed_range(start: u16, register_kind: RegisterKind) -> TypedAddressRange {
+        TypedAddressRange {
+            range: AddressRange { start, count: 1, slave_id: None },
+            register_kind,
+        }
+    }
+
+    #[tokio::test]
+    async fn a_batch_mixing_holding_registers_and_coils_dispatches_each_range_to_its_own_reader() {
+        let ranges = vec![
+            typed_range(0, RegisterKind::Holding),
+            typed_range(10, RegisterKind::Coil),
+        ];
+
+        let results = read_ranges_detailed_by_kind(
+            ranges,
+            |range, kind| async move {
+                assert_eq!(kind, RegisterKind::Holding);
+                Ok(vec![range.start])
+            },
+            |range, kind| async move {
+                assert_eq!(kind, RegisterKind::Coil);
+                assert_eq!(range.start, 10);
+                Ok(vec![true])
+            },
+        )
+        .await;
+
+        assert_eq!(results[0].1.as_ref().unwrap(), &vec![0]);
+        assert_eq!(results[1].1.as_ref().unwrap(), &vec![1]);
+    }
+
+    #[tokio::test]
+    async fn discrete_input_bits_are_converted_to_0_1_register_values() {
+        let ranges = vec![typed_range(0, RegisterKind::DiscreteInput)];
+
+        let results = read_ranges_detailed_by_kind(
+            ranges,
+            |_, _| async move { panic!("discrete inputs must go through read_bits") },
+            |_, _| async move { Ok(vec![true, false, true]) },
+        )
+        .await;
+
+        assert_eq!(results[0].1.as_ref().unwrap(), &vec![1, 0, 1]);
+    }
+
+    #[tokio::test]
+    async fn an_unspecified_register_kind_defaults_to_holding() {
+        assert_eq!(TypedAddressRange::default().register_kind, RegisterKind::Holding);
+    }
+}
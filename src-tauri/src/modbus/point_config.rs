@@ -0,0 +1,135 @@
+use crate::error::AppError;
+
+use super::{AddressRange, DataType};
+
+/// The highest legal bit index within a 16-bit register.
+const MAX_BIT_INDEX: u8 = 15;
+
+/// A configured point: an [`AddressRange`] plus how to interpret the
+/// registers it reads as a single value for [`super::create_address_result`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointConfig {
+    pub range: AddressRange,
+    pub data_type: DataType,
+    pub bit_index: Option<u8>,
+    /// The raw register value representing full scale for an ADC-style
+    /// signal, used to detect and flag saturation. `None` disables the
+    /// check.
+    pub raw_full_scale: Option<u16>,
+    /// Engineering-unit scaling applied by [`super::create_address_result`]
+    /// as `y = raw * scale + offset`, e.g. a raw value of `4000` with
+    /// `scale: Some(0.01)` reporting `40.0`. `None` on either field keeps
+    /// that half of the transform an identity (`1.0` / `0.0`).
+    pub scale: Option<f64>,
+    pub offset: Option<f64>,
+    /// Decimal places the scaled value is rounded to for display. `None`
+    /// leaves it unrounded.
+    pub precision: Option<u32>,
+    /// Display name shown instead of a generic `地址_N` column header,
+    /// e.g. in [`generate_csv_header`].
+    pub label: Option<String>,
+    /// Engineering unit appended to the formatted value for display,
+    /// e.g. `"℃"`.
+    pub unit: Option<String>,
+}
+
+impl PointConfig {
+    /// Build a [`PointConfig`], rejecting a `bit_index` outside the
+    /// 16 bits available in a single register.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        range: AddressRange,
+        data_type: DataType,
+        bit_index: Option<u8>,
+        raw_full_scale: Option<u16>,
+        scale: Option<f64>,
+        offset: Option<f64>,
+        precision: Option<u32>,
+        label: Option<String>,
+        unit: Option<String>,
+    ) -> Result<Self, AppError> {
+        if let Some(index) = bit_index {
+            if index > MAX_BIT_INDEX {
+                return Err(AppError::InvalidConfig(format!(
+                    "bit_index {} 超出寄存器位范围 0..={}",
+                    index, MAX_BIT_INDEX
+                )));
+            }
+        }
+
+        Ok(Self {
+            range,
+            data_type,
+            bit_index,
+            raw_full_scale,
+            scale,
+            offset,
+            precision,
+            label,
+            unit,
+        })
+    }
+}
+
+/// Header row for a CSV export of `points`, one column per point: the
+/// point's `label` when set, otherwise the positional fallback `地址_N`
+/// (1-based) used by older exports that had no label configured.
+pub fn generate_csv_header(points: &[PointConfig]) -> Vec<String> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(index, point)| point.label.clone().unwrap_or_else(|| format!("地址_{}", index + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range() -> AddressRange {
+        AddressRange { start: 0, count: 1, slave_id: None }
+    }
+
+    #[test]
+    fn accepts_bit_index_within_range() {
+        assert!(PointConfig::new(range(), DataType::Bit, Some(15), None, None, None, None, None, None).is_ok());
+    }
+
+    #[test]
+    fn rejects_bit_index_beyond_15() {
+        assert!(PointConfig::new(range(), DataType::Bit, Some(16), None, None, None, None, None, None).is_err());
+    }
+
+    fn labeled_point(label: Option<&str>) -> PointConfig {
+        PointConfig::new(
+            range(),
+            DataType::Bit,
+            Some(0),
+            None,
+            None,
+            None,
+            None,
+            label.map(str::to_string),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn a_labeled_point_uses_its_label_as_the_csv_header() {
+        let points = vec![labeled_point(Some("锅炉温度")), labeled_point(None)];
+
+        let header = generate_csv_header(&points);
+
+        assert_eq!(header, vec!["锅炉温度".to_string(), "地址_2".to_string()]);
+    }
+
+    #[test]
+    fn an_unlabeled_point_falls_back_to_a_1_based_positional_header() {
+        let points = vec![labeled_point(None), labeled_point(None)];
+
+        let header = generate_csv_header(&points);
+
+        assert_eq!(header, vec!["地址_1".to_string(), "地址_2".to_string()]);
+    }
+}
@@ -0,0 +1,70 @@
+use super::AddressRange;
+
+/// Priority tier of a queued read request. Higher-priority reads are
+/// serviced before lower-priority ones when the read queue is backed up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QosLevel {
+    High,
+    Normal,
+    Low,
+}
+
+impl QosLevel {
+    fn rank(self) -> u8 {
+        match self {
+            QosLevel::High => 0,
+            QosLevel::Normal => 1,
+            QosLevel::Low => 2,
+        }
+    }
+}
+
+/// A read request waiting in the queue, tagged with its [`QosLevel`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedRead {
+    pub range: AddressRange,
+    pub qos: QosLevel,
+}
+
+/// Reorder `requests` so higher-priority reads come first. Requests at
+/// the same QoS level keep their original relative order (FIFO within a
+/// tier), since this is a stable sort by rank alone.
+pub fn sort_by_qos(requests: &mut [QueuedRead]) {
+    requests.sort_by_key(|request| request.qos.rank());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u16) -> AddressRange {
+        AddressRange { start, count: 1, slave_id: None }
+    }
+
+    #[test]
+    fn high_priority_reads_come_first_fifo_within_tier() {
+        let mut requests = vec![
+            QueuedRead {
+                range: range(1),
+                qos: QosLevel::Normal,
+            },
+            QueuedRead {
+                range: range(2),
+                qos: QosLevel::High,
+            },
+            QueuedRead {
+                range: range(3),
+                qos: QosLevel::High,
+            },
+            QueuedRead {
+                range: range(4),
+                qos: QosLevel::Low,
+            },
+        ];
+
+        sort_by_qos(&mut requests);
+
+        let order: Vec<u16> = requests.iter().map(|r| r.range.start).collect();
+        assert_eq!(order, vec![2, 3, 1, 4]);
+    }
+}
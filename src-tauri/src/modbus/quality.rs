@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+/// The confidence the caller should place in an [`super::AddressResult`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Quality {
+    /// The value was read and interpreted with no reservations.
+    Good,
+    /// The value looks suspect (e.g. the point is saturated) and should
+    /// be shown with a warning rather than trusted outright.
+    Uncertain,
+}
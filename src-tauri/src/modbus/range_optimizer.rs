@@ -0,0 +1,149 @@
+use super::{AddressRange, DataType};
+
+/// The protocol limit on registers in a single read request (see
+/// [`super::address_range`]'s validation of `count`).
+pub(super) const MAX_REGISTERS_PER_REQUEST: u16 = 125;
+
+/// One request to actually issue on the wire after merging, together
+/// with which of the input ranges (by index) it covers so the caller
+/// can slice the combined result back into per-point values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergedRange {
+    pub range: AddressRange,
+    pub source_indices: Vec<usize>,
+}
+
+/// Merge adjacent or near ranges sharing the same `data_type` into
+/// fewer, larger reads: `[0..10]`, `[10..20]`, `[20..30]` collapse into
+/// a single `[0..30]` request instead of three. Two ranges are only
+/// merged if their `data_type`s match and the gap between them is at
+/// most `max_gap` registers. A merged span that would exceed the
+/// protocol's 125-register limit is split back into chunks no larger
+/// than that; every chunk from the same merged span still lists every
+/// original range it overlaps.
+pub fn optimize_ranges(ranges: &[(AddressRange, DataType)], max_gap: u16) -> Vec<MergedRange> {
+    let mut indexed: Vec<(usize, AddressRange, DataType)> =
+        ranges.iter().enumerate().map(|(i, (range, data_type))| (i, *range, *data_type)).collect();
+    indexed.sort_by_key(|(_, range, _)| range.start);
+
+    let mut merged = Vec::new();
+    // `end` (and therefore `group_end`) is widened to `u32`, the same
+    // way `AddressRange::is_valid` widens before adding: a range ending
+    // exactly at the top of the address space (`start=65535, count=1`)
+    // has an end one past `u16::MAX`, which overflows a `u16` sum.
+    let mut group: Option<(u16, u32, DataType, Vec<usize>)> = None;
+
+    for (index, range, data_type) in indexed {
+        let end = range.start as u32 + range.count as u32;
+        match &mut group {
+            Some((_, group_end, group_type, indices))
+                if *group_type == data_type && range.start as u32 <= *group_end + max_gap as u32 =>
+            {
+                *group_end = (*group_end).max(end);
+                indices.push(index);
+            }
+            _ => {
+                if let Some((start, end, _, indices)) = group.take() {
+                    merged.extend(split_group(start, end, indices));
+                }
+                group = Some((range.start, end, data_type, vec![index]));
+            }
+        }
+    }
+    if let Some((start, end, _, indices)) = group {
+        merged.extend(split_group(start, end, indices));
+    }
+
+    merged
+}
+
+/// Split a merged `[start, end)` span into chunks of at most
+/// [`MAX_REGISTERS_PER_REQUEST`] registers, each tagged with every
+/// source index from the span it came from. `end` is `u32` (see
+/// [`optimize_ranges`]) but every chunk's own `start`/`count` stays
+/// within `u16`, since the span itself never exceeds the address space.
+fn split_group(start: u16, end: u32, indices: Vec<usize>) -> Vec<MergedRange> {
+    let mut chunks = Vec::new();
+    let mut chunk_start = start as u32;
+
+    while chunk_start < end {
+        let chunk_count = (end - chunk_start).min(MAX_REGISTERS_PER_REQUEST as u32);
+        chunks.push(MergedRange {
+            range: AddressRange {
+                start: chunk_start as u16,
+                count: chunk_count as u16,
+                slave_id: None,
+            },
+            source_indices: indices.clone(),
+        });
+        chunk_start += chunk_count;
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u16, count: u16) -> AddressRange {
+        AddressRange { start, count, slave_id: None }
+    }
+
+    #[test]
+    fn three_adjacent_ranges_of_the_same_type_merge_into_one() {
+        let ranges = [
+            (range(0, 10), DataType::Bit),
+            (range(10, 10), DataType::Bit),
+            (range(20, 10), DataType::Bit),
+        ];
+
+        let merged = optimize_ranges(&ranges, 8);
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].range, range(0, 30));
+        assert_eq!(merged[0].source_indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn ranges_with_a_gap_beyond_the_threshold_stay_separate() {
+        let ranges = [(range(0, 10), DataType::Bit), (range(30, 10), DataType::Bit)];
+
+        let merged = optimize_ranges(&ranges, 8);
+
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[test]
+    fn ranges_of_different_data_types_never_merge_even_if_adjacent() {
+        let ranges = [(range(0, 10), DataType::Bit), (range(10, 10), DataType::String)];
+
+        let merged = optimize_ranges(&ranges, 8);
+
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[test]
+    fn a_merged_span_over_125_registers_is_split_into_legal_chunks() {
+        let ranges = [(range(0, 100), DataType::Bit), (range(100, 100), DataType::Bit)];
+
+        let merged = optimize_ranges(&ranges, 0);
+
+        assert!(merged.iter().all(|m| m.range.count <= 125));
+        assert_eq!(merged.iter().map(|m| m.range.count).sum::<u16>(), 200);
+        assert!(merged.iter().all(|m| m.source_indices == vec![0, 1]));
+    }
+
+    #[test]
+    fn a_range_ending_exactly_at_the_top_of_the_address_space_does_not_overflow() {
+        // start=65535, count=1 addresses only register 65535, the
+        // highest legal one — AddressRange::is_valid accepts it, so
+        // optimize_ranges must not panic/wrap computing its end.
+        let ranges = [(range(65535, 1), DataType::Bit)];
+
+        let merged = optimize_ranges(&ranges, 0);
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].range, range(65535, 1));
+    }
+}
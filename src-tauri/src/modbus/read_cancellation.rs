@@ -0,0 +1,102 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::Notify;
+
+use crate::error::AppError;
+
+/// Lets a caller abort an in-flight read instead of waiting out its full
+/// timeout. Unlike [`crate::export::ExportCancellationToken`] (checked
+/// between discrete steps), a read is typically one long `.await`, so
+/// cancelling here wakes it immediately via [`Notify`] rather than
+/// waiting for the next poll of an `AtomicBool`.
+#[derive(Debug, Default, Clone)]
+pub struct ReadCancellationToken {
+    cancelled: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl ReadCancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Signal cancellation, waking any read currently waiting on this
+    /// token via [`read_with_cancellation`].
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Run `read`, returning [`AppError::Cancelled`] instead of its result
+/// if `cancel` is signalled first — either before `read` starts, or
+/// while it's still in flight.
+pub async fn read_with_cancellation<F, Fut>(cancel: &ReadCancellationToken, read: F) -> Result<Vec<u16>, AppError>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<Vec<u16>, AppError>>,
+{
+    // Registering interest in the notification before checking the flag
+    // (rather than after) closes the race where `cancel()` runs between
+    // the check and the `select!` starting to wait.
+    let cancelled = cancel.notify.notified();
+
+    if cancel.is_cancelled() {
+        return Err(AppError::Cancelled);
+    }
+
+    tokio::select! {
+        result = read() => result,
+        _ = cancelled => Err(AppError::Cancelled),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::{sleep, Duration};
+
+    #[tokio::test]
+    async fn a_read_that_finishes_before_cancellation_succeeds() {
+        let cancel = ReadCancellationToken::new();
+
+        let result = read_with_cancellation(&cancel, || async { Ok(vec![1, 2, 3]) }).await;
+
+        assert_eq!(result.unwrap(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn already_cancelled_before_the_read_starts_short_circuits() {
+        let cancel = ReadCancellationToken::new();
+        cancel.cancel();
+
+        let result = read_with_cancellation(&cancel, || async { panic!("read must not run once already cancelled") }).await;
+
+        assert!(matches!(result, Err(AppError::Cancelled)));
+    }
+
+    #[tokio::test]
+    async fn cancelling_mid_read_interrupts_a_read_that_would_otherwise_wait_much_longer() {
+        let cancel = ReadCancellationToken::new();
+        let cancel_for_task = cancel.clone();
+
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(10)).await;
+            cancel_for_task.cancel();
+        });
+
+        let result = read_with_cancellation(&cancel, || async {
+            sleep(Duration::from_secs(10)).await;
+            Ok(vec![1])
+        })
+        .await;
+
+        assert!(matches!(result, Err(AppError::Cancelled)));
+    }
+}
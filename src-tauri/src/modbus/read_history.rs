@@ -0,0 +1,120 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::AddressRange;
+
+/// One recorded read attempt, successful or not, for
+/// [`ReadHistory::recent`] to surface when tracing down an intermittent
+/// fault after the fact.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReadHistoryEntry {
+    pub at: DateTime<Utc>,
+    pub range: AddressRange,
+    pub success: bool,
+    pub duration_ms: u64,
+}
+
+/// A fixed-capacity, in-memory ring buffer of the most recent read
+/// attempts. Oldest entries are dropped once `capacity` is reached, so
+/// memory use stays bounded for a connection that runs for days.
+/// Deliberately not persisted — this is for live troubleshooting, not
+/// an audit trail.
+pub struct ReadHistory {
+    entries: VecDeque<ReadHistoryEntry>,
+    capacity: usize,
+}
+
+impl ReadHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Record one read attempt's outcome and timing.
+    pub fn record(&mut self, at: DateTime<Utc>, range: AddressRange, success: bool, duration: Duration) {
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(ReadHistoryEntry {
+            at,
+            range,
+            success,
+            duration_ms: duration.as_millis() as u64,
+        });
+    }
+
+    /// The most recent `limit` entries, oldest first, capped to however
+    /// many have actually been recorded.
+    pub fn recent(&self, limit: usize) -> Vec<ReadHistoryEntry> {
+        let skip = self.entries.len().saturating_sub(limit);
+        self.entries.iter().skip(skip).cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u16) -> AddressRange {
+        AddressRange { start, count: 1, slave_id: None }
+    }
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn recording_beyond_capacity_drops_the_oldest_entry() {
+        let mut history = ReadHistory::new(2);
+
+        history.record(at(0), range(0), true, Duration::from_millis(1));
+        history.record(at(1), range(1), true, Duration::from_millis(2));
+        history.record(at(2), range(2), true, Duration::from_millis(3));
+
+        assert_eq!(history.len(), 2);
+        let recent = history.recent(10);
+        assert_eq!(recent.iter().map(|e| e.range.start).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn recent_returns_at_most_limit_entries_oldest_first() {
+        let mut history = ReadHistory::new(10);
+        for i in 0..5 {
+            history.record(at(i), range(i as u16), true, Duration::from_millis(1));
+        }
+
+        let recent = history.recent(3);
+
+        assert_eq!(recent.iter().map(|e| e.range.start).collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn requesting_more_than_recorded_returns_everything_available() {
+        let mut history = ReadHistory::new(10);
+        history.record(at(0), range(0), false, Duration::from_millis(5));
+
+        let recent = history.recent(100);
+
+        assert_eq!(recent.len(), 1);
+        assert!(!recent[0].success);
+        assert_eq!(recent[0].duration_ms, 5);
+    }
+
+    #[test]
+    fn a_fresh_history_is_empty() {
+        assert!(ReadHistory::new(10).is_empty());
+    }
+}
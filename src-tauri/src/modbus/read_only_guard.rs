@@ -0,0 +1,73 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::AppError;
+
+/// Runtime kill-switch for write operations. An operator worried about
+/// an accidental write in production flips this on; every write entry
+/// point ([`super::WriteRequest::new`], [`super::WriteCoilRequest::new`])
+/// checks it before building a request, so no write reaches the wire
+/// while it is set — there is no separate path that could bypass it.
+/// Cheap and lock-free to check since it sits on every write call,
+/// mirroring [`super::ConnectionSequencer`]'s use of atomics for
+/// frequently-read state.
+#[derive(Debug, Default)]
+pub struct ReadOnlyGuard {
+    read_only: AtomicBool,
+}
+
+impl ReadOnlyGuard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_read_only(&self, read_only: bool) {
+        self.read_only.store(read_only, Ordering::SeqCst);
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::SeqCst)
+    }
+
+    /// `Err(AppError::WriteForbidden)` while read-only mode is enabled,
+    /// `Ok(())` otherwise. Called at the top of every write constructor
+    /// before any other validation.
+    pub fn check(&self) -> Result<(), AppError> {
+        if self.is_read_only() {
+            return Err(AppError::WriteForbidden);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_fresh_guard_allows_writes() {
+        let guard = ReadOnlyGuard::new();
+
+        assert!(!guard.is_read_only());
+        assert!(guard.check().is_ok());
+    }
+
+    #[test]
+    fn enabling_read_only_mode_rejects_subsequent_checks() {
+        let guard = ReadOnlyGuard::new();
+
+        guard.set_read_only(true);
+
+        assert!(guard.is_read_only());
+        assert!(matches!(guard.check(), Err(AppError::WriteForbidden)));
+    }
+
+    #[test]
+    fn disabling_read_only_mode_allows_writes_again() {
+        let guard = ReadOnlyGuard::new();
+        guard.set_read_only(true);
+
+        guard.set_read_only(false);
+
+        assert!(guard.check().is_ok());
+    }
+}
@@ -0,0 +1,74 @@
+use std::future::Future;
+use std::io;
+use std::time::Duration;
+
+use tokio::time::timeout;
+
+use crate::error::AppError;
+
+/// Run `read` under `timeout_override_ms` if given, otherwise under
+/// `default_timeout_ms` (the connection's configured `timeout_ms`). The
+/// override applies to this single call only and never touches the
+/// connection's own configuration.
+pub async fn read_with_timeout<F, Fut>(
+    default_timeout_ms: u64,
+    timeout_override_ms: Option<u64>,
+    read: F,
+) -> Result<Vec<u16>, AppError>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<Vec<u16>, AppError>>,
+{
+    let effective_timeout_ms = timeout_override_ms.unwrap_or(default_timeout_ms);
+
+    match timeout(Duration::from_millis(effective_timeout_ms), read()).await {
+        Ok(result) => result,
+        Err(_) => Err(AppError::Io(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("read timed out after {effective_timeout_ms}ms"),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::sleep;
+
+    #[tokio::test]
+    async fn an_override_shorter_than_the_read_times_out() {
+        let result = read_with_timeout(1000, Some(5), || async {
+            sleep(Duration::from_millis(50)).await;
+            Ok(vec![1])
+        })
+        .await;
+
+        let err = result.unwrap_err();
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn the_same_read_succeeds_afterwards_under_the_default_timeout() {
+        let timed_out = read_with_timeout(1000, Some(5), || async {
+            sleep(Duration::from_millis(50)).await;
+            Ok(vec![1])
+        })
+        .await;
+        assert!(timed_out.is_err());
+
+        let succeeded = read_with_timeout(1000, None, || async { Ok(vec![42]) }).await;
+
+        assert_eq!(succeeded.unwrap(), vec![42]);
+    }
+
+    #[tokio::test]
+    async fn without_an_override_the_default_timeout_is_used() {
+        let result = read_with_timeout(5, None, || async {
+            sleep(Duration::from_millis(50)).await;
+            Ok(vec![1])
+        })
+        .await;
+
+        assert!(result.is_err());
+    }
+}
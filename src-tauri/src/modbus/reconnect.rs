@@ -0,0 +1,130 @@
+use crate::error::AppError;
+
+/// Reconnect behavior applied when a read fails because the transport
+/// itself dropped, rather than because the device rejected the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    pub auto_reconnect: bool,
+    pub max_reconnect_attempts: u32,
+}
+
+/// Run `read`; if it fails and `policy.auto_reconnect` is enabled,
+/// reconnect via `reconnect` and retry the read, up to
+/// `policy.max_reconnect_attempts` times. A failed `reconnect` call is
+/// returned immediately rather than retried further. If reconnecting is
+/// disabled, the original read failure is returned untouched.
+pub fn ensure_connected<R, C>(policy: &ReconnectPolicy, mut read: R, mut reconnect: C) -> Result<Vec<u16>, AppError>
+where
+    R: FnMut() -> Result<Vec<u16>, AppError>,
+    C: FnMut() -> Result<(), AppError>,
+{
+    let mut last_err = match read() {
+        Ok(values) => return Ok(values),
+        Err(err) => err,
+    };
+
+    if !policy.auto_reconnect {
+        return Err(last_err);
+    }
+
+    for _ in 0..policy.max_reconnect_attempts {
+        reconnect()?;
+        match read() {
+            Ok(values) => return Ok(values),
+            Err(err) => last_err = err,
+        }
+    }
+
+    Err(last_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(auto_reconnect: bool, max_reconnect_attempts: u32) -> ReconnectPolicy {
+        ReconnectPolicy {
+            auto_reconnect,
+            max_reconnect_attempts,
+        }
+    }
+
+    #[test]
+    fn a_successful_read_never_triggers_a_reconnect() {
+        let result = ensure_connected(
+            &policy(true, 3),
+            || Ok(vec![1]),
+            || panic!("reconnect should not be called when the read already succeeded"),
+        );
+        assert_eq!(result.unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn disabled_auto_reconnect_surfaces_the_original_failure() {
+        let result = ensure_connected(
+            &policy(false, 3),
+            || Err(AppError::InvalidConfig("连接已断开".to_string())),
+            || panic!("reconnect should not run when auto_reconnect is disabled"),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn reconnecting_once_lets_the_retried_read_succeed() {
+        let mut read_calls = 0;
+        let mut reconnect_calls = 0;
+
+        let result = ensure_connected(
+            &policy(true, 3),
+            || {
+                read_calls += 1;
+                if read_calls == 1 {
+                    Err(AppError::InvalidConfig("连接已断开".to_string()))
+                } else {
+                    Ok(vec![42])
+                }
+            },
+            || {
+                reconnect_calls += 1;
+                Ok(())
+            },
+        );
+
+        assert_eq!(result.unwrap(), vec![42]);
+        assert_eq!(reconnect_calls, 1);
+    }
+
+    #[test]
+    fn a_failing_reconnect_is_returned_immediately_without_further_retries() {
+        let mut reconnect_calls = 0;
+
+        let result = ensure_connected(
+            &policy(true, 3),
+            || Err(AppError::InvalidConfig("连接已断开".to_string())),
+            || {
+                reconnect_calls += 1;
+                Err(AppError::InvalidConfig("重连失败".to_string()))
+            },
+        );
+
+        assert!(result.is_err());
+        assert_eq!(reconnect_calls, 1);
+    }
+
+    #[test]
+    fn exhausting_every_attempt_returns_the_last_read_failure() {
+        let mut reconnect_calls = 0;
+
+        let result = ensure_connected(
+            &policy(true, 2),
+            || Err(AppError::InvalidConfig("连接已断开".to_string())),
+            || {
+                reconnect_calls += 1;
+                Ok(())
+            },
+        );
+
+        assert!(result.is_err());
+        assert_eq!(reconnect_calls, 2);
+    }
+}
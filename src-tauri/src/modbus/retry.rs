@@ -0,0 +1,98 @@
+use std::future::Future;
+
+use tokio::time::{sleep, Duration};
+
+use crate::error::AppError;
+
+/// How many times (and with what delay) to retry a single read before
+/// giving up. Only applied to [`AppError::is_retryable`] failures —
+/// retrying a request the device itself rejected would just fail again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub retry_attempts: u32,
+    pub retry_delay_ms: u64,
+}
+
+/// Run `read`, retrying up to `policy.retry_attempts` times (waiting
+/// `policy.retry_delay_ms` between attempts) as long as each failure is
+/// retryable. A non-retryable failure, or exhausting every attempt, is
+/// returned as-is.
+pub async fn read_with_retry<F, Fut>(policy: &RetryPolicy, mut read: F) -> Result<Vec<u16>, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Vec<u16>, AppError>>,
+{
+    let mut attempt = 0;
+    loop {
+        match read().await {
+            Ok(values) => return Ok(values),
+            Err(err) if attempt < policy.retry_attempts && err.is_retryable() => {
+                attempt += 1;
+                eprintln!("warn: 第 {attempt} 次重试读取，原因：{err}");
+                sleep(Duration::from_millis(policy.retry_delay_ms)).await;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn policy(retry_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            retry_attempts,
+            retry_delay_ms: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn succeeds_after_failing_n_times() {
+        let calls = AtomicUsize::new(0);
+
+        let result = read_with_retry(&policy(3), || {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if call < 2 {
+                    Err(AppError::Io(std::io::Error::other("timeout")))
+                } else {
+                    Ok(vec![9])
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), vec![9]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn a_device_error_is_never_retried() {
+        let calls = AtomicUsize::new(0);
+
+        let result = read_with_retry(&policy(3), || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Err(AppError::InvalidConfig("非法地址".to_string())) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn exhausting_every_retry_returns_the_last_failure() {
+        let calls = AtomicUsize::new(0);
+
+        let result = read_with_retry(&policy(2), || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Err(AppError::Io(std::io::Error::other("timeout"))) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,31 @@
+/// Baud rates commonly used by Modbus RTU devices, tried in this order
+/// when the configured rate is unknown.
+pub const COMMON_BAUD_RATES: [u32; 6] = [9600, 19200, 38400, 57600, 115200, 4800];
+
+/// Probe `candidates` in order using `probe`, returning the first baud
+/// rate that yields a successful exchange. `probe` encapsulates opening
+/// the serial port at a given rate and issuing a test read, so this
+/// function stays free of any actual I/O and is easy to unit test.
+pub fn detect_baud_rate<F>(candidates: &[u32], mut probe: F) -> Option<u32>
+where
+    F: FnMut(u32) -> bool,
+{
+    candidates.iter().copied().find(|&rate| probe(rate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_first_candidate_that_succeeds() {
+        let found = detect_baud_rate(&COMMON_BAUD_RATES, |rate| rate == 38400);
+        assert_eq!(found, Some(38400));
+    }
+
+    #[test]
+    fn returns_none_when_no_candidate_succeeds() {
+        let found = detect_baud_rate(&COMMON_BAUD_RATES, |_| false);
+        assert_eq!(found, None);
+    }
+}
@@ -0,0 +1,185 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::error::AppError;
+
+use super::{sort_by_qos, AddressRange, QueuedRead};
+
+/// Abstracts the network read a [`Scheduler`] dispatches, so the
+/// scheduling logic (priority, concurrency, rate limiting) can be unit
+/// tested against a fake implementation instead of a real connection.
+pub trait ModbusReader: Send + Sync {
+    fn read(&self, range: AddressRange) -> Pin<Box<dyn Future<Output = Result<Vec<u16>, AppError>> + Send>>;
+}
+
+type ScheduledResult = (AddressRange, Result<Vec<u16>, AppError>);
+
+/// Dispatches a queue of [`QueuedRead`]s to a [`ModbusReader`], ordering
+/// them by [`super::QosLevel`] (see [`sort_by_qos`]), capping how many
+/// reads may be in flight at once, and spacing successive dispatches at
+/// least `min_dispatch_interval` apart. Decoupled from the network layer
+/// so scheduling behaviour can be verified against a fake reader.
+pub struct Scheduler {
+    reader: Arc<dyn ModbusReader>,
+    max_concurrency: usize,
+    min_dispatch_interval: Duration,
+}
+
+impl Scheduler {
+    pub fn new(reader: Arc<dyn ModbusReader>, max_concurrency: usize, min_dispatch_interval: Duration) -> Self {
+        Self {
+            reader,
+            max_concurrency,
+            min_dispatch_interval,
+        }
+    }
+
+    /// Run every queued read to completion, highest [`super::QosLevel`]
+    /// first, returning each range's outcome. Results may complete out
+    /// of dispatch order, but every range in `queue` is represented
+    /// exactly once.
+    pub async fn run(&self, mut queue: Vec<QueuedRead>) -> Vec<ScheduledResult> {
+        sort_by_qos(&mut queue);
+
+        let semaphore = Arc::new(Semaphore::new(self.max_concurrency));
+        let mut pending = JoinSet::new();
+
+        for (index, item) in queue.into_iter().enumerate() {
+            if index > 0 {
+                tokio::time::sleep(self.min_dispatch_interval).await;
+            }
+
+            let semaphore = semaphore.clone();
+            let reader = self.reader.clone();
+            pending.spawn(async move {
+                let _permit = semaphore.acquire_owned().await.expect("scheduler semaphore is never closed");
+                (item.range, reader.read(item.range).await)
+            });
+        }
+
+        let mut results = Vec::new();
+        while let Some(outcome) = pending.join_next().await {
+            results.push(outcome.expect("scheduled read task panicked"));
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    use super::super::QosLevel;
+
+    struct FakeReader {
+        read_duration: Duration,
+        in_flight: Arc<AtomicUsize>,
+        peak_in_flight: Arc<AtomicUsize>,
+    }
+
+    impl ModbusReader for FakeReader {
+        fn read(&self, range: AddressRange) -> Pin<Box<dyn Future<Output = Result<Vec<u16>, AppError>> + Send>> {
+            let read_duration = self.read_duration;
+            let in_flight = self.in_flight.clone();
+            let peak_in_flight = self.peak_in_flight.clone();
+            Box::pin(async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak_in_flight.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(read_duration).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(vec![range.start])
+            })
+        }
+    }
+
+    fn range(start: u16) -> AddressRange {
+        AddressRange { start, count: 1, slave_id: None }
+    }
+
+    fn queue(count: u16) -> Vec<QueuedRead> {
+        (0..count)
+            .map(|i| QueuedRead {
+                range: range(i),
+                qos: QosLevel::Normal,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn rate_limit_and_concurrency_cap_are_both_enforced_at_once() {
+        const MAX_CONCURRENCY: usize = 2;
+        const MIN_DISPATCH_INTERVAL: Duration = Duration::from_millis(20);
+        const READ_COUNT: u16 = 5;
+
+        let reader = Arc::new(FakeReader {
+            read_duration: Duration::from_millis(60),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            peak_in_flight: Arc::new(AtomicUsize::new(0)),
+        });
+        let scheduler = Scheduler::new(reader.clone(), MAX_CONCURRENCY, MIN_DISPATCH_INTERVAL);
+
+        let started = Instant::now();
+        let results = scheduler.run(queue(READ_COUNT)).await;
+        let elapsed = started.elapsed();
+
+        assert_eq!(results.len(), READ_COUNT as usize);
+        assert!(
+            reader.peak_in_flight.load(Ordering::SeqCst) <= MAX_CONCURRENCY,
+            "peak concurrency exceeded the configured cap"
+        );
+        assert!(
+            elapsed >= MIN_DISPATCH_INTERVAL * (READ_COUNT as u32 - 1),
+            "dispatches were not spaced by the configured rate limit: {elapsed:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn higher_qos_reads_are_dispatched_before_lower_ones_under_a_single_concurrency_slot() {
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        struct RecordingReader {
+            order: Arc<Mutex<Vec<u16>>>,
+        }
+
+        impl ModbusReader for RecordingReader {
+            fn read(&self, range: AddressRange) -> Pin<Box<dyn Future<Output = Result<Vec<u16>, AppError>> + Send>> {
+                self.order.lock().unwrap().push(range.start);
+                Box::pin(async move { Ok(vec![range.start]) })
+            }
+        }
+
+        let scheduler = Scheduler::new(Arc::new(RecordingReader { order: order.clone() }), 1, Duration::from_millis(0));
+        let queue = vec![
+            QueuedRead { range: range(1), qos: QosLevel::Low },
+            QueuedRead { range: range(2), qos: QosLevel::High },
+        ];
+
+        scheduler.run(queue).await;
+
+        assert_eq!(*order.lock().unwrap(), vec![2, 1]);
+    }
+
+    #[tokio::test]
+    async fn every_queued_range_is_represented_exactly_once_in_the_results() {
+        let reader = Arc::new(FakeReader {
+            read_duration: Duration::from_millis(1),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            peak_in_flight: Arc::new(AtomicUsize::new(0)),
+        });
+        let scheduler = Scheduler::new(reader, 4, Duration::from_millis(0));
+
+        let results = scheduler.run(queue(4)).await;
+
+        let mut starts: Vec<u16> = results.into_iter().map(|(range, _)| range.start).collect();
+        starts.sort();
+        assert_eq!(starts, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,126 @@
+use std::future::Future;
+
+use crate::error::AppError;
+
+use super::AddressRange;
+
+/// One range's outcome from [`read_ranges_with_slave_routing`]: the
+/// registers read, or the error that range's read hit.
+type RoutedRangeResult = (AddressRange, Result<Vec<u16>, AppError>);
+
+/// Read every range in `ranges` in order against its own
+/// [`AddressRange::slave_id`] (falling back to `default_slave_id` when
+/// unset), switching the connection's active slave via `set_slave` only
+/// when it actually differs from the one last used. Unlike
+/// [`super::read_ranges_detailed`], this always reads sequentially:
+/// pipelining slave switches on a shared connection would race.
+pub async fn read_ranges_with_slave_routing<S, R, Fut>(
+    ranges: Vec<AddressRange>,
+    default_slave_id: u8,
+    mut set_slave: S,
+    mut read_range: R,
+) -> Vec<RoutedRangeResult>
+where
+    S: FnMut(u8),
+    R: FnMut(AddressRange) -> Fut,
+    Fut: Future<Output = Result<Vec<u16>, AppError>>,
+{
+    let mut current_slave = default_slave_id;
+    let mut results = Vec::with_capacity(ranges.len());
+
+    for range in ranges {
+        let slave_id = range.slave_id.unwrap_or(default_slave_id);
+        if slave_id != current_slave {
+            set_slave(slave_id);
+            current_slave = slave_id;
+        }
+        let outcome = read_range(range).await;
+        results.push((range, outcome));
+    }
+
+    if current_slave != default_slave_id {
+        set_slave(default_slave_id);
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn range(start: u16, slave_id: Option<u8>) -> AddressRange {
+        AddressRange {
+            start,
+            count: 1,
+            slave_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn each_range_is_routed_to_its_own_slave() {
+        let routed_to = Mutex::new(Vec::new());
+
+        let results = read_ranges_with_slave_routing(
+            vec![range(0, Some(2)), range(10, Some(5)), range(20, None)],
+            1,
+            |slave| routed_to.lock().unwrap().push(slave),
+            |r| async move { Ok(vec![r.start]) },
+        )
+        .await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|(_, outcome)| outcome.is_ok()));
+        // 1 -> 2, 2 -> 5, then the unset range falls back to the
+        // default slave 1, so no extra restore is needed at the end.
+        assert_eq!(*routed_to.lock().unwrap(), vec![2, 5, 1]);
+    }
+
+    #[tokio::test]
+    async fn a_redundant_switch_to_the_same_slave_is_skipped() {
+        let switch_calls = Mutex::new(Vec::new());
+
+        read_ranges_with_slave_routing(
+            vec![range(0, Some(2)), range(10, Some(2)), range(20, Some(3))],
+            1,
+            |slave| switch_calls.lock().unwrap().push(slave),
+            |r| async move { Ok(vec![r.start]) },
+        )
+        .await;
+
+        // 1 -> 2 (switch), 2 -> 2 (skipped), 2 -> 3 (switch), then
+        // restored back to the default slave 1 at the end.
+        assert_eq!(*switch_calls.lock().unwrap(), vec![2, 3, 1]);
+    }
+
+    #[tokio::test]
+    async fn unset_slave_ids_fall_back_to_the_default_without_switching() {
+        let switch_calls = Mutex::new(Vec::new());
+
+        read_ranges_with_slave_routing(
+            vec![range(0, None), range(10, None)],
+            7,
+            |slave| switch_calls.lock().unwrap().push(slave),
+            |r| async move { Ok(vec![r.start]) },
+        )
+        .await;
+
+        assert!(switch_calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn ending_on_a_non_default_slave_restores_the_default_afterward() {
+        let switch_calls = Mutex::new(Vec::new());
+
+        read_ranges_with_slave_routing(
+            vec![range(0, Some(9))],
+            1,
+            |slave| switch_calls.lock().unwrap().push(slave),
+            |r| async move { Ok(vec![r.start]) },
+        )
+        .await;
+
+        assert_eq!(*switch_calls.lock().unwrap(), vec![9, 1]);
+    }
+}
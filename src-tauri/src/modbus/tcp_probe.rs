@@ -0,0 +1,76 @@
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::error::AppError;
+
+/// Probe whether `host:port` is reachable before attempting the real
+/// Modbus session, so a connection failure can be reported as either
+/// "host unreachable" or "port not open" instead of one generic
+/// timeout. Limited to a TCP connect attempt (no ICMP echo) since that
+/// needs no elevated privileges and no extra dependency, unlike ICMP
+/// which typically requires a raw socket.
+///
+/// Distinguishes the two failure modes by `io::Error::kind`:
+/// `ConnectionRefused` means something answered at `host` and actively
+/// rejected the port (nothing listening there), while every other
+/// connect failure (timeout, no route, DNS failure) is reported as the
+/// host itself being unreachable.
+///
+/// This crate has no TCP-based Modbus connect wrapper yet for this to
+/// run in front of — callers wiring it in ahead of the real connect
+/// attempt should pass `timeout` as half of the configured connect
+/// timeout, so the probe plus the real connect together still fit
+/// within the original budget.
+pub fn probe_tcp_reachable(host: &str, port: u16, timeout: Duration) -> Result<(), AppError> {
+    let addr = (host, port)
+        .to_socket_addrs()
+        .map_err(|_| AppError::InvalidConfig("主机不可达".to_string()))?
+        .next()
+        .ok_or_else(|| AppError::InvalidConfig("主机不可达".to_string()))?;
+
+    match TcpStream::connect_timeout(&addr, timeout) {
+        Ok(_) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::ConnectionRefused => {
+            Err(AppError::InvalidConfig("端口未开放".to_string()))
+        }
+        Err(_) => Err(AppError::InvalidConfig("主机不可达".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn a_listening_port_is_reported_reachable() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let result = probe_tcp_reachable("127.0.0.1", addr.port(), Duration::from_millis(200));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn a_closed_port_on_a_reachable_host_is_reported_as_port_not_open() {
+        // Bind then immediately drop the listener: the port is released
+        // back to the OS, so a connect attempt gets ECONNREFUSED rather
+        // than hanging — exercising the exact distinction this function
+        // is meant to draw.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let result = probe_tcp_reachable("127.0.0.1", addr.port(), Duration::from_millis(200));
+
+        assert_eq!(result.unwrap_err().to_string(), AppError::InvalidConfig("端口未开放".to_string()).to_string());
+    }
+
+    #[test]
+    fn an_unresolvable_host_is_reported_as_unreachable() {
+        let result = probe_tcp_reachable("this.host.does.not.exist.invalid", 502, Duration::from_millis(200));
+
+        assert_eq!(result.unwrap_err().to_string(), AppError::InvalidConfig("主机不可达".to_string()).to_string());
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::{HashMap, VecDeque};
+
+/// Multiplier applied to a device's mean observed RTT to get a
+/// recommended initial timeout, leaving headroom for normal variance.
+const TIMEOUT_MULTIPLIER: u64 = 3;
+
+/// Floor for a recommended timeout, so a device with an unrealistically
+/// fast recorded RTT doesn't get an unusably tight timeout.
+const MIN_RECOMMENDED_TIMEOUT_MS: u64 = 200;
+
+/// Learns a per-device response-time profile from observed round trips,
+/// keyed by `"ip:port:slave_id"`, and recommends an initial read timeout
+/// for devices it has seen before instead of a hand-tuned guess.
+#[derive(Debug, Default)]
+pub struct DeviceTimeoutProfile {
+    capacity: usize,
+    history: HashMap<String, VecDeque<u64>>,
+}
+
+impl DeviceTimeoutProfile {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            history: HashMap::new(),
+        }
+    }
+
+    /// Record an observed round-trip time for `device_key`.
+    pub fn record_rtt(&mut self, device_key: &str, rtt_ms: u64) {
+        let samples = self.history.entry(device_key.to_string()).or_default();
+        if samples.len() == self.capacity {
+            samples.pop_front();
+        }
+        samples.push_back(rtt_ms);
+    }
+
+    /// The recommended initial timeout for `device_key`, or `None` if no
+    /// history has been recorded for it yet.
+    pub fn recommended_timeout_ms(&self, device_key: &str) -> Option<u64> {
+        let samples = self.history.get(device_key)?;
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mean = samples.iter().sum::<u64>() / samples.len() as u64;
+        Some((mean * TIMEOUT_MULTIPLIER).max(MIN_RECOMMENDED_TIMEOUT_MS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_device_has_no_recommendation() {
+        let profile = DeviceTimeoutProfile::new(10);
+        assert_eq!(profile.recommended_timeout_ms("10.0.0.1:502:1"), None);
+    }
+
+    #[test]
+    fn recommendation_falls_within_a_reasonable_multiple_of_historical_rtt() {
+        let mut profile = DeviceTimeoutProfile::new(10);
+        let device = "10.0.0.1:502:1";
+        for rtt in [50, 60, 55, 45, 50] {
+            profile.record_rtt(device, rtt);
+        }
+
+        let recommended = profile.recommended_timeout_ms(device).unwrap();
+
+        assert!(recommended >= 50 * 2, "timeout should cover mean RTT with margin: {recommended}");
+        assert!(recommended <= 50 * 10, "timeout should not wildly overshoot mean RTT: {recommended}");
+    }
+
+    #[test]
+    fn oldest_sample_is_dropped_beyond_capacity() {
+        let mut profile = DeviceTimeoutProfile::new(2);
+        let device = "10.0.0.1:502:1";
+        profile.record_rtt(device, 10);
+        profile.record_rtt(device, 20);
+        profile.record_rtt(device, 30);
+
+        let recommended = profile.recommended_timeout_ms(device).unwrap();
+
+        assert_eq!(recommended, MIN_RECOMMENDED_TIMEOUT_MS.max(25 * TIMEOUT_MULTIPLIER));
+    }
+}
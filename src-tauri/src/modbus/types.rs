@@ -0,0 +1,19 @@
+/// Byte order used when serializing a `u16` register to its two-byte
+/// wire representation. Shared by every module that needs to turn raw
+/// registers into bytes (hex export, multi-register decoding, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    BigEndian,
+    LittleEndian,
+}
+
+/// Register (word) order for values spanning multiple registers, i.e.
+/// which register holds the most significant 16 bits. Independent of
+/// [`ByteOrder`], which only governs byte order *within* one register.
+/// Some devices send 32/64-bit values "word swapped" relative to their
+/// natural byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordOrder {
+    HighFirst,
+    LowFirst,
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::AppError;
+
+/// A single linear unit conversion: `value_in_to_unit = value * factor + offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct UnitConversion {
+    pub factor: f64,
+    #[serde(default)]
+    pub offset: f64,
+}
+
+/// A table of [`UnitConversion`]s keyed by `(from_unit, to_unit)`, so
+/// custom conversions beyond the few built in can be loaded from a file
+/// instead of requiring a rebuild.
+#[derive(Debug, Clone, Default)]
+pub struct UnitConversionTable {
+    conversions: HashMap<(String, String), UnitConversion>,
+}
+
+impl UnitConversionTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, from_unit: &str, to_unit: &str, conversion: UnitConversion) {
+        self.conversions.insert((from_unit.to_string(), to_unit.to_string()), conversion);
+    }
+
+    /// Convert `value` from `from_unit` to `to_unit`. Converting a unit
+    /// to itself always succeeds regardless of the table's contents;
+    /// any other pair with no registered rule returns `None`.
+    pub fn convert(&self, value: f64, from_unit: &str, to_unit: &str) -> Option<f64> {
+        if from_unit == to_unit {
+            return Some(value);
+        }
+
+        let conversion = self.conversions.get(&(from_unit.to_string(), to_unit.to_string()))?;
+        Some(value * conversion.factor + conversion.offset)
+    }
+}
+
+/// One entry in the JSON file loaded by [`load_unit_conversions`].
+#[derive(Debug, Deserialize)]
+struct UnitConversionEntry {
+    from_unit: String,
+    to_unit: String,
+    factor: f64,
+    #[serde(default)]
+    offset: f64,
+}
+
+/// Load a JSON array of unit conversion rules from `file_path` into a
+/// fresh [`UnitConversionTable`]. Expected shape:
+/// `[{"from_unit": "psi", "to_unit": "bar", "factor": 0.0689476}, ...]`.
+pub fn load_unit_conversions(file_path: &Path) -> Result<UnitConversionTable, AppError> {
+    let contents = std::fs::read_to_string(file_path)?;
+    let entries: Vec<UnitConversionEntry> =
+        serde_json::from_str(&contents).map_err(|err| AppError::InvalidConfig(err.to_string()))?;
+
+    let mut table = UnitConversionTable::new();
+    for entry in entries {
+        table.insert(
+            &entry.from_unit,
+            &entry.to_unit,
+            UnitConversion {
+                factor: entry.factor,
+                offset: entry.offset,
+            },
+        );
+    }
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn unique_temp_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("modbus_recoder_unit_conversions_{name}.json"));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn a_custom_psi_to_bar_conversion_loaded_from_file_produces_the_correct_output() {
+        let path = unique_temp_file("psi_bar");
+        std::fs::write(&path, r#"[{"from_unit": "psi", "to_unit": "bar", "factor": 0.0689476}]"#).unwrap();
+
+        let table = load_unit_conversions(&path).unwrap();
+        let bar = table.convert(100.0, "psi", "bar").unwrap();
+
+        assert!((bar - 6.89476).abs() < 1e-9, "expected ~6.89476 bar, got {bar}");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn converting_a_unit_to_itself_is_always_a_no_op() {
+        let table = UnitConversionTable::new();
+        assert_eq!(table.convert(42.0, "celsius", "celsius"), Some(42.0));
+    }
+
+    #[test]
+    fn an_unregistered_pair_returns_none() {
+        let table = UnitConversionTable::new();
+        assert_eq!(table.convert(1.0, "psi", "bar"), None);
+    }
+
+    #[test]
+    fn an_offset_is_applied_after_scaling() {
+        let mut table = UnitConversionTable::new();
+        table.insert("celsius", "fahrenheit", UnitConversion { factor: 1.8, offset: 32.0 });
+
+        assert_eq!(table.convert(100.0, "celsius", "fahrenheit"), Some(212.0));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::error::AppError;
+
+use super::ReadOnlyGuard;
+
+/// Modbus function code 16 (0x10) allows at most this many registers in
+/// a single "write multiple registers" request.
+const MAX_WRITE_REGISTERS: usize = 123;
+
+/// A validated batch write of consecutive holding registers, ready to be
+/// sent as a Modbus "write multiple registers" (function code 16) request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteRequest {
+    pub start: u16,
+    pub values: Vec<u16>,
+}
+
+impl WriteRequest {
+    /// Build a [`WriteRequest`], rejecting batches that are empty or
+    /// exceed the protocol's per-request register limit. Checked against
+    /// `guard` before anything else, so a write attempted while
+    /// [`ReadOnlyGuard::is_read_only`] is set never even gets as far as
+    /// validating its shape.
+    pub fn new(guard: &ReadOnlyGuard, start: u16, values: Vec<u16>) -> Result<Self, AppError> {
+        guard.check()?;
+        if values.is_empty() {
+            return Err(AppError::InvalidConfig(
+                "写入寄存器列表不能为空".to_string(),
+            ));
+        }
+        if values.len() > MAX_WRITE_REGISTERS {
+            return Err(AppError::InvalidConfig(format!(
+                "单次写入寄存器数量 {} 超过协议上限 {}",
+                values.len(),
+                MAX_WRITE_REGISTERS
+            )));
+        }
+
+        Ok(Self { start, values })
+    }
+}
+
+/// Function code 05: write a single coil. The Modbus wire protocol
+/// represents `true` as `0xFF00` and `false` as `0x0000`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteCoilRequest {
+    pub address: u16,
+    pub value: bool,
+}
+
+impl WriteCoilRequest {
+    /// Checked against `guard` before being built, for the same reason
+    /// as [`WriteRequest::new`].
+    pub fn new(guard: &ReadOnlyGuard, address: u16, value: bool) -> Result<Self, AppError> {
+        guard.check()?;
+        Ok(Self { address, value })
+    }
+
+    /// The 16-bit value sent on the wire for this coil write.
+    pub fn to_wire_value(&self) -> u16 {
+        if self.value {
+            0xFF00
+        } else {
+            0x0000
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coil_on_encodes_as_0xff00() {
+        let guard = ReadOnlyGuard::new();
+        assert_eq!(WriteCoilRequest::new(&guard, 10, true).unwrap().to_wire_value(), 0xFF00);
+    }
+
+    #[test]
+    fn coil_off_encodes_as_0x0000() {
+        let guard = ReadOnlyGuard::new();
+        assert_eq!(WriteCoilRequest::new(&guard, 10, false).unwrap().to_wire_value(), 0x0000);
+    }
+
+    #[test]
+    fn rejects_empty_write() {
+        let guard = ReadOnlyGuard::new();
+        assert!(WriteRequest::new(&guard, 0, vec![]).is_err());
+    }
+
+    #[test]
+    fn rejects_batches_over_the_protocol_limit() {
+        let guard = ReadOnlyGuard::new();
+        let values = vec![0u16; MAX_WRITE_REGISTERS + 1];
+        assert!(WriteRequest::new(&guard, 0, values).is_err());
+    }
+
+    #[test]
+    fn accepts_a_legal_batch() {
+        let guard = ReadOnlyGuard::new();
+        let request = WriteRequest::new(&guard, 100, vec![1, 2, 3]).unwrap();
+        assert_eq!(request.start, 100);
+        assert_eq!(request.values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn read_only_mode_rejects_a_register_write_request() {
+        let guard = ReadOnlyGuard::new();
+        guard.set_read_only(true);
+
+        let result = WriteRequest::new(&guard, 0, vec![1]);
+
+        assert!(matches!(result, Err(AppError::WriteForbidden)));
+    }
+
+    #[test]
+    fn read_only_mode_rejects_a_coil_write_request() {
+        let guard = ReadOnlyGuard::new();
+        guard.set_read_only(true);
+
+        let result = WriteCoilRequest::new(&guard, 0, true);
+
+        assert!(matches!(result, Err(AppError::WriteForbidden)));
+    }
+
+    #[test]
+    fn turning_read_only_mode_back_off_allows_writes_again() {
+        let guard = ReadOnlyGuard::new();
+        guard.set_read_only(true);
+        guard.set_read_only(false);
+
+        assert!(WriteRequest::new(&guard, 0, vec![1]).is_ok());
+        assert!(WriteCoilRequest::new(&guard, 0, true).is_ok());
+    }
+}